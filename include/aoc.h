@@ -0,0 +1,63 @@
+/* Advent of Code: C interface
+ *
+ * Generated from src/capi.rs, don't edit. Regenerate with
+ * `AOC_UPDATE_SNAPSHOTS=1 cargo test capi`.
+ */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Status of a call to the C interface */
+typedef enum AocStatus {
+    /* Answer was written to the output buffer */
+    AOC_OK = 0,
+    /* No solution for the given day or part */
+    AOC_UNKNOWN_PUZZLE = 1,
+    /* Input isn't valid UTF-8 or can't be parsed */
+    AOC_INVALID_INPUT = 2,
+    /* Solution failed to answer the part */
+    AOC_FAILED = 3,
+    /* Output buffer is too small for the answer */
+    AOC_BUFFER_TOO_SMALL = 4,
+    /* Required pointer argument is null */
+    AOC_NULL_POINTER = 5,
+    /* Solution panicked */
+    AOC_PANIC = 6,
+} AocStatus;
+
+/* Output buffer provided by the caller */
+typedef struct AocBuffer {
+    /* Pointer to `capacity` writable bytes, may be null if `capacity` is 0 */
+    char *data;
+    /* Size of the buffer in bytes, including the terminating NUL */
+    size_t capacity;
+    /* Length of the answer or error message in bytes, excluding the
+     * terminating NUL. Output was truncated if it isn't less than
+     * `capacity`. */
+    size_t len;
+} AocBuffer;
+
+/* Answer the given part of a puzzle of 2022 for the given input. On
+ * success, the answer is written to the output buffer, otherwise an error
+ * message. `len` of the output buffer is set to the full length of the
+ * answer or message, so a call with an empty buffer gives the required
+ * size. `input` may be null if `len` is 0. */
+AocStatus aoc_solve(uint32_t day, uint32_t part, const uint8_t *input, size_t len,
+                    AocBuffer *out);
+
+/* Describe the given status. The returned string is static and must not
+ * be freed. */
+const char *aoc_status_message(int status);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC_H */
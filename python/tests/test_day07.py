@@ -0,0 +1,45 @@
+from advent_of_code_2022.day07 import Filesystem
+import pytest
+
+TERMINAL_OUTPUT = """$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+"""
+
+
+def test_filesystem():
+    fs = Filesystem(TERMINAL_OUTPUT)
+    assert fs.used_size() == 48381165
+    assert sorted(fs.dir_sizes()) == [584, 94853, 24933642, 48381165]
+    assert fs.sum_of_dir_sizes(100_000) == 95437
+    assert fs.size_of_dir_to_delete(70_000_000, 30_000_000) == 24933642
+
+
+def test_errors():
+    with pytest.raises(ValueError, match="^Parse error at line 3"):
+        Filesystem("$ cd /\n$ ls\nfoo bar\n")
+    fs = Filesystem(TERMINAL_OUTPUT)
+    with pytest.raises(RuntimeError, match="^Invalid puzzle state: used size exceeds"):
+        fs.size_of_dir_to_delete(40_000_000, 30_000_000)
+    with pytest.raises(RuntimeError, match="^Unsolvable puzzle: no directory"):
+        fs.size_of_dir_to_delete(70_000_000, 80_000_000)
@@ -0,0 +1,28 @@
+from advent_of_code_2022.day10 import Cpu
+import pytest
+
+
+def test_step():
+    cpu = Cpu("noop\naddx 3\naddx -5\n")
+    assert (cpu.x, cpu.cycles) == (1, 0)
+    assert cpu.step() == 1
+    assert (cpu.x, cpu.cycles) == (1, 1)
+    assert cpu.step() == 2
+    assert (cpu.x, cpu.cycles) == (4, 3)
+    assert cpu.step() == 2
+    assert (cpu.x, cpu.cycles) == (-1, 5)
+    assert cpu.step() == 0
+
+
+def test_run():
+    cpu = Cpu("noop\n" * 240)
+    signal_strength, crt = cpu.run()
+    assert signal_strength == (20 + 60 + 100 + 140 + 180 + 220) * 1
+    assert crt == ("###" + " " * 37 + "\n") * 6
+    assert cpu.cycles == 240
+    assert cpu.run() == (0, "")
+
+
+def test_parse_error():
+    with pytest.raises(ValueError, match="^Parse error at line 2: invalid instruction `jmp`"):
+        Cpu("noop\njmp 3\n")
@@ -0,0 +1,46 @@
+import advent_of_code_2022 as aoc
+import pytest
+
+CALORIES = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n"
+
+PROCEDURE = """    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+"""
+
+
+def test_days():
+    assert aoc.days(2022) == list(range(1, 11))
+    assert aoc.days(2015) == []
+
+
+def test_day_modules():
+    for day in aoc.days(2022):
+        module = getattr(aoc, f"day{day:02}")
+        assert callable(module.solve)
+
+
+def test_solve():
+    assert aoc.solve(2022, 1, 1, CALORIES) == 24000
+    assert aoc.day01.solve(CALORIES, 1) == 24000
+    assert aoc.day01.solve(CALORIES, part=2) == 45000
+
+
+def test_text_answer():
+    assert aoc.day05.solve(PROCEDURE, 1) == "CMZ"
+    assert aoc.day05.solve(PROCEDURE, 2) == "MCD"
+
+
+def test_errors():
+    with pytest.raises(ValueError, match="^Parse error at line 1: invalid digit"):
+        aoc.day01.solve("x\n", 1)
+    with pytest.raises(ValueError, match="^no part 3 in 2022 day 1$"):
+        aoc.day01.solve(CALORIES, 3)
+    with pytest.raises(ValueError, match="^no puzzle 2015 day 1$"):
+        aoc.solve(2015, 1, 1, CALORIES)
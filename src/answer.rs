@@ -0,0 +1,301 @@
+//! Advent of Code: puzzle answers
+//!
+//! Recorded answers (`input/answers.tsv`) have one answer per line with tab
+//! separated year, day, part and answer. Line breaks in answers are escaped
+//! as `\n`.
+
+use crate::error::{Error, Result};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::input::INPUT_PATH;
+use crate::ocr;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::fmt;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+#[cfg(not(target_arch = "wasm32"))]
+use std::io;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Name of the recorded answers file in the input path
+#[cfg(not(target_arch = "wasm32"))]
+const ANSWERS: &str = "answers.tsv";
+
+/// Answer to a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Number
+    Integer(i128),
+    /// Text, e.g. letters of crates
+    Text(String),
+    /// Pixel image, e.g. letters drawn on a display. An image matches a
+    /// text answer of the letters it shows (see [`Answer::matches`]).
+    Image(Vec<Vec<bool>>),
+}
+
+impl Answer {
+    /// Whether this answer matches the given expected answer. Unlike
+    /// equality, an image matches a text answer of the letters it shows.
+    #[must_use]
+    pub fn matches(&self, expected: &Self) -> bool {
+        match (self, expected) {
+            (Self::Image(image), Self::Text(text)) | (Self::Text(text), Self::Image(image)) => {
+                ocr::recognize_pixels(image).is_ok_and(|letters| letters == *text)
+            }
+            _ => self == expected,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+            Self::Image(rows) => {
+                let rows = rows.iter().map(|row| {
+                    row.iter()
+                        .map(|&lit| if lit { '#' } else { '.' })
+                        .collect::<String>()
+                });
+                write!(f, "{}", rows.format("\n"))
+            }
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Parse answer. Numbers are integer answers, multiple lines of `#` and
+    /// `.` (or space) are image answers, anything else is a text answer.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let is_pixels = |line: &str| line.chars().all(|c| matches!(c, '#' | '.' | ' '));
+        if let Ok(n) = s.parse() {
+            Ok(Self::Integer(n))
+        } else if s.lines().count() > 1 && s.lines().all(is_pixels) {
+            let rows = s
+                .lines()
+                .map(|line| line.chars().map(|c| c == '#').collect());
+            Ok(Self::Image(rows.collect()))
+        } else {
+            Ok(Self::Text(s.to_string()))
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Self::Integer(n.into())
+            }
+        })*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Integer(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_string())
+    }
+}
+
+impl From<Vec<Vec<bool>>> for Answer {
+    fn from(rows: Vec<Vec<bool>>) -> Self {
+        Self::Image(rows)
+    }
+}
+
+/// Recorded answers of puzzles, by year, day and part
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u16, usize, u8), Answer>);
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| parse_line(line).map_err(|e| e.at_line(i + 1)))
+            .try_collect()
+            .map(Self)
+    }
+}
+
+/// Parse line of the answers file
+fn parse_line(line: &str) -> Result<((u16, usize, u8), Answer)> {
+    let (year, day, part, answer) = line
+        .splitn(4, '\t')
+        .collect_tuple()
+        .ok_or_else(|| Error::parse(format!("expected 4 tab separated fields in `{line}`")))?;
+    let Ok(answer) = answer.replace("\\n", "\n").parse::<Answer>();
+    Ok(((year.parse()?, day.parse()?, part.parse()?), answer))
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((year, day, part), answer) in &self.0 {
+            let answer = answer.to_string().replace('\n', "\\n");
+            writeln!(f, "{year}\t{day}\t{part}\t{answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Answers {
+    /// Path to the recorded answers file
+    fn path() -> PathBuf {
+        Path::new(INPUT_PATH).join(ANSWERS)
+    }
+
+    /// Load recorded answers from the input path. A missing file has no
+    /// answers.
+    ///
+    /// # Errors
+    /// I/O error or invalid answers file
+    pub fn load() -> Result<Self> {
+        match fs::read_to_string(Self::path()) {
+            Ok(s) => s.parse(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Save recorded answers to the input path
+    ///
+    /// # Errors
+    /// I/O error
+    pub fn save(&self) -> io::Result<()> {
+        fs::write(Self::path(), self.to_string())
+    }
+}
+
+impl Answers {
+    /// Recorded answer to the given part of a puzzle
+    #[must_use]
+    pub fn get(&self, year: u16, day: usize, part: u8) -> Option<&Answer> {
+        self.0.get(&(year, day, part))
+    }
+
+    /// Record answer to the given part of a puzzle
+    pub fn insert(&mut self, year: u16, day: usize, part: u8, answer: Answer) {
+        self.0.insert((year, day, part), answer);
+    }
+
+    /// Check the given answer against the recorded answer. Answers that
+    /// weren't recorded are always correct.
+    ///
+    /// # Errors
+    /// Wrong answer
+    pub fn check(&self, year: u16, day: usize, part: u8, answer: &Answer) -> Result<()> {
+        match self.get(year, day, part) {
+            Some(expected) if !answer.matches(expected) => Err(Error::WrongAnswer {
+                expected: expected.to_string(),
+                actual: answer.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IMAGE: &str = "\
+        ####.####..##..####.###..#..#.###..####\n\
+        #....#....#..#.#....#..#.#..#.#..#.#...\n\
+        ###..###..#....###..#..#.#..#.#..#.###.\n\
+        #....#....#.##.#....###..#..#.###..#...\n\
+        #....#....#..#.#....#.#..#..#.#.#..#...\n\
+        ####.#.....###.####.#..#..##..#..#.####";
+
+    #[test]
+    fn parse_and_display() {
+        for s in ["42", "-7", "CMZ", IMAGE] {
+            assert_eq!(s.parse::<Answer>().unwrap().to_string(), s);
+        }
+        assert_eq!("42".parse::<Answer>().unwrap(), Answer::Integer(42));
+        assert_eq!("4 2".parse::<Answer>().unwrap(), Answer::from("4 2"));
+        assert!(matches!(IMAGE.parse().unwrap(), Answer::Image(_)));
+    }
+
+    #[test]
+    fn conversion() {
+        assert_eq!(Answer::from(42_u32), Answer::Integer(42));
+        assert_eq!(Answer::from(-42_i32), Answer::Integer(-42));
+        assert_eq!(Answer::from(42_usize), Answer::Integer(42));
+        assert_eq!(Answer::from("CMZ".to_string()), Answer::Text("CMZ".into()));
+        assert_ne!(Answer::from(42_u8), Answer::from("42"));
+    }
+
+    #[test]
+    fn image_matches_letters() {
+        let image: Answer = IMAGE.parse().unwrap();
+        assert!(image.matches(&Answer::from("EFGERURE")));
+        assert!(Answer::from("EFGERURE").matches(&image));
+        assert!(!image.matches(&Answer::from("EFGERUR")));
+        assert_ne!(image, Answer::from("EFGERURE"));
+        assert!(Answer::Integer(42).matches(&Answer::Integer(42)));
+        assert!(!Answer::Integer(42).matches(&Answer::from("42")));
+    }
+
+    #[test]
+    fn answers_file() {
+        let s = "2022\t1\t1\t24000\n2022\t5\t1\tCMZ\n2022\t10\t2\t#.\\n.#\n";
+        let answers: Answers = s.parse().unwrap();
+        assert_eq!(answers.get(2022, 1, 1), Some(&Answer::Integer(24000)));
+        assert_eq!(answers.get(2022, 1, 2), None);
+        assert_eq!(
+            answers.get(2022, 10, 2),
+            Some(&Answer::Image(vec![vec![true, false], vec![false, true]]))
+        );
+        assert_eq!(answers.to_string(), s);
+        let err = "2022\t1\t1\t1\n2022 1 2 3\n"
+            .parse::<Answers>()
+            .unwrap_err();
+        assert!(matches!(err, Error::Parse { line: Some(2), .. }));
+    }
+
+    #[test]
+    fn check() {
+        let answers: Answers = "2022\t1\t1\t24000\n".parse().unwrap();
+        assert!(answers.check(2022, 1, 1, &24000_u32.into()).is_ok());
+        assert!(answers.check(2022, 1, 2, &1_u32.into()).is_ok());
+        let err = answers.check(2022, 1, 1, &1_u32.into()).unwrap_err();
+        assert_eq!(err.to_string(), "Wrong answer 1 (expected 24000)");
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn recorded_answers() {
+        let answers = Answers::load().unwrap();
+        let expected = Answer::from("EFGERURE");
+        assert!(answers
+            .get(2022, 10, 2)
+            .is_some_and(|a| a.matches(&expected)));
+        assert_eq!(
+            answers.to_string(),
+            fs::read_to_string(Answers::path()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,436 @@
+//! Advent of Code: compact sets of small integers and characters
+//!
+//! A [`BitSet64`] stores a set of integers below 64 in a single `u64`, so
+//! set algebra and population count are single instructions. A [`CharSet`]
+//! maps letters and digits onto a [`BitSet64`].
+
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::{BitAnd, BitOr, BitXor, Not, Sub};
+
+/// Set of integers in `0..64`
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BitSet64(u64);
+
+impl BitSet64 {
+    /// Largest value (exclusive) that can be stored
+    pub const CAPACITY: u32 = u64::BITS;
+
+    /// Empty set
+    pub const EMPTY: Self = Self(0);
+
+    /// Set of all integers in `0..64`
+    pub const FULL: Self = Self(u64::MAX);
+
+    /// Create empty set
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Create set from its bits, bit `i` representing integer `i`
+    #[must_use]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    /// Bits of this set, bit `i` representing integer `i`
+    #[must_use]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Bit representing the given value
+    fn bit(value: u32) -> u64 {
+        assert!(value < Self::CAPACITY, "{value} out of range for BitSet64");
+        1 << value
+    }
+
+    /// Add the given value, returns whether it was newly added
+    ///
+    /// # Panics
+    /// Value not below 64
+    pub fn insert(&mut self, value: u32) -> bool {
+        let added = !self.contains(value);
+        self.0 |= Self::bit(value);
+        added
+    }
+
+    /// Remove the given value, returns whether it was present
+    ///
+    /// # Panics
+    /// Value not below 64
+    pub fn remove(&mut self, value: u32) -> bool {
+        let present = self.contains(value);
+        self.0 &= !Self::bit(value);
+        present
+    }
+
+    /// Add the given value if it's absent, remove it if it's present
+    ///
+    /// # Panics
+    /// Value not below 64
+    pub fn toggle(&mut self, value: u32) {
+        self.0 ^= Self::bit(value);
+    }
+
+    /// Whether the given value is in this set
+    #[must_use]
+    pub fn contains(self, value: u32) -> bool {
+        value < Self::CAPACITY && self.0 & (1 << value) != 0
+    }
+
+    /// Number of values in this set
+    #[must_use]
+    pub const fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Whether this set is empty
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Smallest value in this set
+    #[must_use]
+    pub const fn min(self) -> Option<u32> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.0.trailing_zeros())
+        }
+    }
+
+    /// Values in this or the other set
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Values in both this and the other set
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Values in this but not in the other set
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Values in exactly one of this and the other set
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    /// Whether all values of this set are in the other set
+    #[must_use]
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Values in ascending order
+    #[must_use]
+    pub const fn iter(self) -> Iter {
+        Iter(self.0)
+    }
+}
+
+impl fmt::Debug for BitSet64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over values of a [`BitSet64`] in ascending order
+#[derive(Debug, Clone)]
+pub struct Iter(u64);
+
+impl Iterator for Iter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let value = BitSet64(self.0).min()?;
+        self.0 &= self.0 - 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl FusedIterator for Iter {}
+
+impl IntoIterator for BitSet64 {
+    type Item = u32;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl FromIterator<u32> for BitSet64 {
+    fn from_iter<I: IntoIterator<Item = u32>>(values: I) -> Self {
+        let mut set = Self::new();
+        set.extend(values);
+        set
+    }
+}
+
+impl Extend<u32> for BitSet64 {
+    fn extend<I: IntoIterator<Item = u32>>(&mut self, values: I) {
+        for value in values {
+            self.insert(value);
+        }
+    }
+}
+
+/// Set of ASCII letters and digits
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CharSet(BitSet64);
+
+impl CharSet {
+    /// Empty set
+    pub const EMPTY: Self = Self(BitSet64::EMPTY);
+
+    /// Create empty set
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Index of the given character: `a`-`z` are 0-25, `A`-`Z` are 26-51
+    /// and `0`-`9` are 52-61
+    #[must_use]
+    pub fn index(ch: char) -> Option<u32> {
+        match ch {
+            'a'..='z' => Some(ch as u32 - 'a' as u32),
+            'A'..='Z' => Some(ch as u32 - 'A' as u32 + 26),
+            '0'..='9' => Some(ch as u32 - '0' as u32 + 52),
+            _ => None,
+        }
+    }
+
+    /// Character of the given index
+    fn char(index: u32) -> char {
+        let (base, offset) = match index {
+            0..=25 => ('a', index),
+            26..=51 => ('A', index - 26),
+            _ => ('0', index - 52),
+        };
+        char::from_u32(base as u32 + offset).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+
+    /// Add the given character, returns whether it was newly added.
+    /// Characters other than ASCII letters and digits are ignored.
+    pub fn insert(&mut self, ch: char) -> bool {
+        Self::index(ch).is_some_and(|index| self.0.insert(index))
+    }
+
+    /// Remove the given character, returns whether it was present
+    pub fn remove(&mut self, ch: char) -> bool {
+        Self::index(ch).is_some_and(|index| self.0.remove(index))
+    }
+
+    /// Add the given character if it's absent, remove it if it's present.
+    /// Characters other than ASCII letters and digits are ignored.
+    pub fn toggle(&mut self, ch: char) {
+        if let Some(index) = Self::index(ch) {
+            self.0.toggle(index);
+        }
+    }
+
+    /// Whether the given character is in this set
+    #[must_use]
+    pub fn contains(self, ch: char) -> bool {
+        Self::index(ch).is_some_and(|index| self.0.contains(index))
+    }
+
+    /// Number of characters in this set
+    #[must_use]
+    pub const fn len(self) -> u32 {
+        self.0.len()
+    }
+
+    /// Whether this set is empty
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Characters in this or the other set
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0.union(other.0))
+    }
+
+    /// Characters in both this and the other set
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0.intersection(other.0))
+    }
+
+    /// Characters in this but not in the other set
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0.difference(other.0))
+    }
+
+    /// Characters in exactly one of this and the other set
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0.symmetric_difference(other.0))
+    }
+
+    /// Whether all characters of this set are in the other set
+    #[must_use]
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.0.is_subset(other.0)
+    }
+
+    /// Characters in order of their index (lowercase letters, uppercase
+    /// letters, digits)
+    pub fn iter(self) -> impl Iterator<Item = char> {
+        self.0.iter().map(Self::char)
+    }
+}
+
+impl fmt::Debug for CharSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<&str> for CharSet {
+    fn from(s: &str) -> Self {
+        s.chars().collect()
+    }
+}
+
+impl FromIterator<char> for CharSet {
+    fn from_iter<I: IntoIterator<Item = char>>(chars: I) -> Self {
+        let mut set = Self::new();
+        set.extend(chars);
+        set
+    }
+}
+
+impl Extend<char> for CharSet {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, chars: I) {
+        for ch in chars {
+            self.insert(ch);
+        }
+    }
+}
+
+macro_rules! impl_set_ops {
+    ($($set:ty),*) => {
+        $(impl BitOr for $set {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                self.union(rhs)
+            }
+        }
+
+        impl BitAnd for $set {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                self.intersection(rhs)
+            }
+        }
+
+        impl Sub for $set {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                self.difference(rhs)
+            }
+        }
+
+        impl BitXor for $set {
+            type Output = Self;
+
+            fn bitxor(self, rhs: Self) -> Self {
+                self.symmetric_difference(rhs)
+            }
+        })*
+    };
+}
+
+impl_set_ops!(BitSet64, CharSet);
+
+impl Not for BitSet64 {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(!self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitset() {
+        let mut set: BitSet64 = [3, 1, 63].into_iter().collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(63));
+        assert!(!set.contains(64));
+        assert!(!set.insert(3));
+        assert!(set.remove(3));
+        assert!(!set.remove(3));
+        set.toggle(5);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [1, 5, 63]);
+        assert_eq!(set.min(), Some(1));
+        assert_eq!(format!("{set:?}"), "{1, 5, 63}");
+        assert_eq!(BitSet64::EMPTY.min(), None);
+        assert_eq!((!set).len(), 61);
+    }
+
+    #[test]
+    #[should_panic(expected = "64 out of range")]
+    fn bitset_out_of_range() {
+        BitSet64::new().insert(64);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a: BitSet64 = [1, 2, 3].into_iter().collect();
+        let b: BitSet64 = [3, 4].into_iter().collect();
+        assert_eq!((a | b).iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!((a & b).iter().collect::<Vec<_>>(), [3]);
+        assert_eq!((a - b).iter().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!((a ^ b).iter().collect::<Vec<_>>(), [1, 2, 4]);
+        assert!((a & b).is_subset(a));
+        assert!(!a.is_subset(b));
+    }
+
+    #[test]
+    fn charset() {
+        let a = CharSet::from("vJrwpWtwJgWr");
+        let b = CharSet::from("hcsFMMfFFhFp");
+        assert_eq!((a & b).iter().collect::<String>(), "p");
+        assert_eq!(CharSet::from("zA9a").iter().collect::<String>(), "azA9");
+        assert_eq!(CharSet::from("abcabc").len(), 3);
+        let mut set = CharSet::from("a-b");
+        assert_eq!(set.len(), 2);
+        assert!(!set.contains('-'));
+        assert!(!set.insert('!'));
+        set.toggle('a');
+        set.toggle('Z');
+        assert_eq!(format!("{set:?}"), "{'b', 'Z'}");
+        assert_eq!(CharSet::index('Z'), Some(51));
+        assert_eq!(CharSet::index('9'), Some(61));
+    }
+}
@@ -0,0 +1,343 @@
+//! Advent of Code: C interface
+//!
+//! Exposes puzzle solutions to C and C++ through the shared library, with
+//! the declarations in `include/aoc.h` (generated by [`header`]). No memory
+//! is passed across the interface: callers provide the buffer for answers
+//! and error messages, status messages are static strings.
+
+use crate::error::describe;
+use crate::registry::{self, SolveError};
+use crate::YEAR;
+use std::ffi::{c_char, c_int, CStr};
+use std::fmt::Write;
+use std::panic;
+use std::{ptr, slice};
+
+/// Path of the generated C header
+pub const HEADER_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/include/aoc.h");
+
+/// Status of a call to the C interface
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Answer was written to the output buffer
+    Ok = 0,
+    /// No solution for the given day or part
+    UnknownPuzzle = 1,
+    /// Input isn't valid UTF-8 or can't be parsed
+    InvalidInput = 2,
+    /// Solution failed to answer the part
+    Failed = 3,
+    /// Output buffer is too small for the answer
+    BufferTooSmall = 4,
+    /// Required pointer argument is null
+    NullPointer = 5,
+    /// Solution panicked
+    Panic = 6,
+}
+
+impl Status {
+    /// All statuses, in order of their values
+    const ALL: [Self; 7] = [
+        Self::Ok,
+        Self::UnknownPuzzle,
+        Self::InvalidInput,
+        Self::Failed,
+        Self::BufferTooSmall,
+        Self::NullPointer,
+        Self::Panic,
+    ];
+
+    /// Name of the constant in C
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Ok => "AOC_OK",
+            Self::UnknownPuzzle => "AOC_UNKNOWN_PUZZLE",
+            Self::InvalidInput => "AOC_INVALID_INPUT",
+            Self::Failed => "AOC_FAILED",
+            Self::BufferTooSmall => "AOC_BUFFER_TOO_SMALL",
+            Self::NullPointer => "AOC_NULL_POINTER",
+            Self::Panic => "AOC_PANIC",
+        }
+    }
+
+    /// Description of the status
+    const fn message(self) -> &'static CStr {
+        match self {
+            Self::Ok => c"answer was written to the output buffer",
+            Self::UnknownPuzzle => c"no solution for the given day or part",
+            Self::InvalidInput => c"input isn't valid UTF-8 or can't be parsed",
+            Self::Failed => c"solution failed to answer the part",
+            Self::BufferTooSmall => c"output buffer is too small for the answer",
+            Self::NullPointer => c"required pointer argument is null",
+            Self::Panic => c"solution panicked",
+        }
+    }
+}
+
+/// Output buffer provided by the caller
+#[repr(C)]
+#[derive(Debug)]
+pub struct Buffer {
+    /// Pointer to `capacity` writable bytes, may be null if `capacity` is 0
+    pub data: *mut c_char,
+    /// Size of the buffer in bytes, including the terminating NUL
+    pub capacity: usize,
+    /// Length of the answer or error message in bytes, excluding the
+    /// terminating NUL. Output was truncated if it isn't less than
+    /// `capacity`.
+    pub len: usize,
+}
+
+impl Buffer {
+    /// Write the given text, truncated to the capacity and NUL-terminated.
+    /// Returns whether it fit completely.
+    ///
+    /// # Safety
+    /// `data` must point to `capacity` writable bytes
+    unsafe fn write(&mut self, s: &str) -> bool {
+        self.len = s.len();
+        if self.data.is_null() || self.capacity == 0 {
+            return false;
+        }
+        let n = s.len().min(self.capacity - 1);
+        // SAFETY: `n + 1` bytes fit in the buffer and `s` doesn't overlap it
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), self.data.cast(), n);
+            *self.data.add(n) = 0;
+        }
+        n == s.len()
+    }
+}
+
+/// Answer the given part of a puzzle, or fail with a status and an error
+/// message
+fn solve(day: u32, part: u32, input: &[u8]) -> Result<String, (Status, String)> {
+    let day = usize::try_from(day).unwrap_or(usize::MAX);
+    // Parts beyond `u8` don't exist either
+    let part = u8::try_from(part).map_err(|_| {
+        (
+            Status::UnknownPuzzle,
+            format!("no part {part} in {YEAR} day {day}"),
+        )
+    })?;
+    let input = std::str::from_utf8(input).map_err(|e| {
+        (
+            Status::InvalidInput,
+            format!("input is not valid UTF-8: {e}"),
+        )
+    })?;
+    registry::solve(YEAR, day, part, input)
+        .map(|answer| answer.to_string())
+        .map_err(|e| match e {
+            SolveError::UnknownPuzzle { .. } | SolveError::UnknownPart { .. } => {
+                (Status::UnknownPuzzle, e.to_string())
+            }
+            SolveError::Parse(e) => (Status::InvalidInput, describe(&e)),
+            SolveError::Solve(e) => (Status::Failed, describe(&e)),
+        })
+}
+
+/// Answer the given part of a puzzle of [`YEAR`] for the given input. On
+/// success, the answer is written to the output buffer, otherwise an error
+/// message. `len` of the output buffer is set to the full length of the
+/// answer or message, so a call with an empty buffer gives the required
+/// size.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes (may be null if `len` is 0),
+/// `out` must point to a [`Buffer`] whose `data` points to `capacity`
+/// writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input: *const u8,
+    len: usize,
+    out: *mut Buffer,
+) -> Status {
+    // SAFETY: `out` is null or points to a valid buffer
+    let Some(out) = (unsafe { out.as_mut() }) else {
+        return Status::NullPointer;
+    };
+    let input = match len {
+        0 => &[][..],
+        _ if input.is_null() => {
+            // SAFETY: the buffer is valid
+            unsafe { out.write("input is null") };
+            return Status::NullPointer;
+        }
+        // SAFETY: `input` points to `len` readable bytes
+        _ => unsafe { slice::from_raw_parts(input, len) },
+    };
+    let res = panic::catch_unwind(|| solve(day, part, input))
+        .unwrap_or_else(|_| Err((Status::Panic, "solver panicked".to_string())));
+    // SAFETY: the buffer is valid
+    match res {
+        Ok(answer) if unsafe { out.write(&answer) } => Status::Ok,
+        Ok(_) => Status::BufferTooSmall,
+        Err((status, message)) => {
+            unsafe { out.write(&message) };
+            status
+        }
+    }
+}
+
+/// Describe the given status. The returned string is static and must not
+/// be freed.
+#[no_mangle]
+pub extern "C" fn aoc_status_message(status: c_int) -> *const c_char {
+    Status::ALL
+        .into_iter()
+        .find(|s| *s as c_int == status)
+        .map_or(c"unknown status", Status::message)
+        .as_ptr()
+}
+
+/// C header declaring the interface
+#[must_use]
+pub fn header() -> String {
+    let mut statuses = String::new();
+    for status in Status::ALL {
+        let message = status.message().to_string_lossy();
+        let _ = write!(
+            statuses,
+            "\n    /* {}{} */\n    {} = {},",
+            message[..1].to_uppercase(),
+            &message[1..],
+            status.name(),
+            status as c_int
+        );
+    }
+    format!(
+        "/* Advent of Code: C interface
+ *
+ * Generated from src/capi.rs, don't edit. Regenerate with
+ * `AOC_UPDATE_SNAPSHOTS=1 cargo test capi`.
+ */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+/* Status of a call to the C interface */
+typedef enum AocStatus {{{statuses}
+}} AocStatus;
+
+/* Output buffer provided by the caller */
+typedef struct AocBuffer {{
+    /* Pointer to `capacity` writable bytes, may be null if `capacity` is 0 */
+    char *data;
+    /* Size of the buffer in bytes, including the terminating NUL */
+    size_t capacity;
+    /* Length of the answer or error message in bytes, excluding the
+     * terminating NUL. Output was truncated if it isn't less than
+     * `capacity`. */
+    size_t len;
+}} AocBuffer;
+
+/* Answer the given part of a puzzle of {YEAR} for the given input. On
+ * success, the answer is written to the output buffer, otherwise an error
+ * message. `len` of the output buffer is set to the full length of the
+ * answer or message, so a call with an empty buffer gives the required
+ * size. `input` may be null if `len` is 0. */
+AocStatus aoc_solve(uint32_t day, uint32_t part, const uint8_t *input, size_t len,
+                    AocBuffer *out);
+
+/* Describe the given status. The returned string is static and must not
+ * be freed. */
+const char *aoc_status_message(int status);
+
+#ifdef __cplusplus
+}}
+#endif
+
+#endif /* AOC_H */
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::line_diff;
+    use std::fs;
+
+    /// Call `aoc_solve` with a buffer of the given capacity
+    fn call(day: u32, part: u32, input: &[u8], capacity: usize) -> (Status, String, usize) {
+        let mut data = vec![b'?'; capacity];
+        let mut out = Buffer {
+            data: data.as_mut_ptr().cast(),
+            capacity,
+            len: usize::MAX,
+        };
+        let status = unsafe { aoc_solve(day, part, input.as_ptr(), input.len(), &raw mut out) };
+        let written = data.split(|&b| b == 0).next().unwrap_or_default();
+        (status, String::from_utf8_lossy(written).into(), out.len)
+    }
+
+    #[test]
+    fn solve_into_buffer() {
+        let input = b"1\n2\n\n4\n";
+        assert_eq!(call(1, 2, input, 8), (Status::Ok, "7".to_string(), 1));
+        assert_eq!(
+            call(1, 2, input, 1),
+            (Status::BufferTooSmall, String::new(), 1)
+        );
+        let message = "no part 3 in 2022 day 1";
+        assert_eq!(
+            call(1, 3, input, 64),
+            (Status::UnknownPuzzle, message.to_string(), 23)
+        );
+        assert_eq!(
+            call(1, 3, input, 8),
+            (Status::UnknownPuzzle, message[..7].to_string(), 23)
+        );
+        assert_eq!(call(1, 1, b"\xff\n", 64).0, Status::InvalidInput);
+        assert_eq!(call(26, 1, input, 64).1, "no puzzle 2022 day 26");
+    }
+
+    #[test]
+    fn null_pointers() {
+        let mut out = Buffer {
+            data: ptr::null_mut(),
+            capacity: 0,
+            len: 0,
+        };
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 1, &raw mut out) };
+        assert_eq!((status, out.len), (Status::NullPointer, 13));
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 0, &raw mut out) };
+        assert_eq!((status, out.len), (Status::BufferTooSmall, 1));
+        let status = unsafe { aoc_solve(1, 1, b"1\n".as_ptr(), 2, ptr::null_mut()) };
+        assert_eq!(status, Status::NullPointer);
+    }
+
+    #[test]
+    fn status_messages() {
+        let message = |status| unsafe { CStr::from_ptr(aoc_status_message(status)) };
+        assert_eq!(message(0), c"answer was written to the output buffer");
+        assert_eq!(message(Status::Panic as c_int), c"solution panicked");
+        assert_eq!(message(-1), c"unknown status");
+    }
+
+    #[test]
+    fn generated_header() {
+        let header = header();
+        if std::env::var("AOC_UPDATE_SNAPSHOTS").is_ok_and(|value| value == "1") {
+            fs::write(HEADER_PATH, &header).unwrap();
+        }
+        let checked_in = fs::read_to_string(HEADER_PATH).unwrap();
+        assert!(
+            checked_in == header,
+            "{HEADER_PATH} is outdated (-checked in, +generated):\n{}\n\
+            Run with AOC_UPDATE_SNAPSHOTS=1 to regenerate it",
+            line_diff(&checked_in, &header)
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Advent of Code: puzzle input checksums
+//!
+//! A manifest (`input/checksums.sha256`, in `sha256sum` format) records the
+//! SHA-256 checksum of every input, so that accidentally modified inputs are
+//! detected when they're opened.
+
+use crate::crypt;
+use crate::input::{find_files, read_file, INPUT_PATH};
+use itertools::Itertools;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Name of the manifest file in the input path
+const MANIFEST: &str = "checksums.sha256";
+
+/// Environment variable that selects how checksum mismatches are handled
+/// (`strict` (default), `warn` or `off`)
+const VERIFY_VAR: &str = "AOC_INPUT_VERIFY";
+
+/// Checksum mismatch
+#[derive(Debug, Error)]
+#[error("Checksum mismatch for input {0} (refresh checksums if this change was intended)")]
+pub struct ChecksumMismatch(String);
+
+/// Invalid manifest line
+#[derive(Debug, Error)]
+#[error("Invalid checksum manifest line: {0}")]
+pub struct InvalidManifest(String);
+
+/// Calculate hex representation of SHA-256 checksum of the given data
+#[must_use]
+pub fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .fold(String::new(), |mut s, byte| {
+            let _ = write!(s, "{byte:02x}");
+            s
+        })
+}
+
+/// Manifest of input checksums
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Manifest(BTreeMap<String, String>);
+
+impl FromStr for Manifest {
+    type Err = InvalidManifest;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| match line.split_once("  ") {
+                Some((checksum, name)) => Ok((name.to_string(), checksum.to_string())),
+                None => Err(InvalidManifest(line.to_string())),
+            })
+            .try_collect()
+            .map(Self)
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, checksum) in &self.0 {
+            writeln!(f, "{checksum}  {name}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Manifest {
+    /// Path to the manifest file
+    fn path() -> PathBuf {
+        Path::new(INPUT_PATH).join(MANIFEST)
+    }
+
+    /// Load manifest from the input path. A missing manifest is empty.
+    ///
+    /// # Errors
+    /// I/O error or invalid manifest
+    pub fn load() -> io::Result<Self> {
+        match fs::read_to_string(Self::path()) {
+            Ok(s) => s
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Save manifest to the input path
+    ///
+    /// # Errors
+    /// I/O error
+    pub fn save(&self) -> io::Result<()> {
+        fs::write(Self::path(), self.to_string())
+    }
+
+    /// Create manifest with checksums of all (plain text or encrypted)
+    /// inputs in the input path
+    ///
+    /// # Errors
+    /// I/O error or decryption failure
+    pub fn calculate() -> io::Result<Self> {
+        let dir = Path::new(INPUT_PATH);
+        let mut filenames = find_files(dir, "txt")?;
+        for encrypted in find_files(dir, "enc")? {
+            filenames.push(encrypted.with_extension(""));
+        }
+        let mut manifest = Self::default();
+        for filename in filenames.into_iter().sorted().dedup() {
+            let data = read_file(&filename)?;
+            manifest.insert(&filename, &data);
+        }
+        Ok(manifest)
+    }
+
+    /// Verify all inputs recorded in this manifest, returns the number of
+    /// verified inputs. Encrypted inputs are skipped if there's no input key.
+    ///
+    /// # Errors
+    /// I/O error, decryption failure or checksum mismatch
+    pub fn verify_inputs(&self) -> io::Result<usize> {
+        let mut verified = 0;
+        for name in self.0.keys() {
+            let filename = Path::new(INPUT_PATH).join(name);
+            let data = match read_file(&filename) {
+                Err(e) if crypt::is_no_key(&e) => continue,
+                res => res?,
+            };
+            self.check(&filename, &data)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            verified += 1;
+        }
+        Ok(verified)
+    }
+
+    /// Number of checksums in this manifest
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether this manifest is empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Record checksum of the given input file
+    pub fn insert(&mut self, filename: &Path, data: &[u8]) {
+        self.0.insert(name(filename), sha256_hex(data));
+    }
+
+    /// Check the given input file against its recorded checksum. Inputs
+    /// without a recorded checksum are always valid.
+    ///
+    /// # Errors
+    /// Checksum mismatch
+    pub fn check(&self, filename: &Path, data: &[u8]) -> Result<(), ChecksumMismatch> {
+        let name = name(filename);
+        match self.0.get(&name) {
+            Some(checksum) if *checksum != sha256_hex(data) => Err(ChecksumMismatch(name)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Name of the given input file in the manifest, i.e. relative to the input path
+fn name(filename: &Path) -> String {
+    let name = filename.strip_prefix(INPUT_PATH).unwrap_or(filename);
+    name.iter().map(|s| s.to_string_lossy()).join("/")
+}
+
+/// Verify the given input file against the manifest. Depending on the
+/// `AOC_INPUT_VERIFY` environment variable, a mismatch fails (`strict`,
+/// default), is returned as warning for the caller to report (`warn`) or is
+/// ignored (`off`).
+///
+/// # Errors
+/// I/O error, invalid manifest or checksum mismatch
+pub fn verify(filename: &Path, data: &[u8]) -> io::Result<Option<ChecksumMismatch>> {
+    let mode = std::env::var(VERIFY_VAR).unwrap_or_default();
+    if mode == "off" {
+        return Ok(None);
+    }
+    match Manifest::load()?.check(filename, data) {
+        Ok(()) => Ok(None),
+        Err(e) if mode == "warn" => Ok(Some(e)),
+        Err(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+    }
+}
+
+/// Recalculate checksums of all inputs and save the manifest
+///
+/// # Errors
+/// I/O error or decryption failure
+pub fn refresh() -> io::Result<Manifest> {
+    let manifest = Manifest::calculate()?;
+    manifest.save()?;
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECKSUMS: &str = "\
+        ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  2022/day01.txt\n\
+        e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  day01.txt\n";
+
+    #[test]
+    fn checksum() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn parse_and_format() {
+        let manifest: Manifest = CHECKSUMS.parse().unwrap();
+        assert_eq!(manifest.len(), 2);
+        assert_eq!(manifest.to_string(), CHECKSUMS);
+        assert!("invalid".parse::<Manifest>().is_err());
+    }
+
+    #[test]
+    fn check() {
+        let manifest: Manifest = CHECKSUMS.parse().unwrap();
+        let filename = Path::new(INPUT_PATH).join("2022/day01.txt");
+        assert!(manifest.check(&filename, b"abc").is_ok());
+        assert!(manifest.check(&filename, b"abc\n").is_err());
+        assert!(manifest.check(Path::new("day02.txt"), b"abc").is_ok());
+    }
+
+    #[test]
+    fn name_of_input() {
+        let filename = Path::new(INPUT_PATH).join("2022/day07.txt");
+        assert_eq!(name(&filename), "2022/day07.txt");
+    }
+
+    #[test]
+    fn inputs_match_manifest() {
+        Manifest::load().unwrap().verify_inputs().unwrap();
+    }
+}
@@ -0,0 +1,241 @@
+//! Advent of Code: encrypted puzzle inputs
+//!
+//! Personal puzzle inputs may be stored encrypted next to (or instead of)
+//! plain text inputs. Encrypted inputs use `ChaCha20Poly1305` with a random
+//! nonce that is prepended to the ciphertext.
+
+use crate::input::{find_files, INPUT_PATH};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use std::ffi::OsString;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Environment variable with the hex-encoded input key
+const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+/// Environment variable with the path to the input key file
+const KEY_FILE_VAR: &str = "AOC_INPUT_KEY_FILE";
+
+/// Default path to the input key file
+const KEY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/.input-key");
+
+/// File extension appended to encrypted inputs
+const EXTENSION: &str = "enc";
+
+/// Length of nonce prepended to encrypted data
+const NONCE_LEN: usize = 12;
+
+/// Invalid input key
+#[derive(Debug, Error)]
+#[error("Invalid input key (expected 64 hex digits)")]
+pub struct InvalidKey;
+
+/// No input key configured
+#[derive(Debug, Error)]
+#[error("No input key (set {KEY_VAR} or create key file {})", .0.display())]
+pub struct NoKey(PathBuf);
+
+/// Symmetric key for encrypting and decrypting puzzle inputs
+#[derive(Clone)]
+pub struct Key(chacha20poly1305::Key);
+
+impl FromStr for Key {
+    type Err = InvalidKey;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.len() != 64 || !s.is_ascii() {
+            return Err(InvalidKey);
+        }
+        let mut key = chacha20poly1305::Key::default();
+        for (byte, hex) in key.iter_mut().zip(s.as_bytes().chunks(2)) {
+            let hex = std::str::from_utf8(hex).map_err(|_| InvalidKey)?;
+            *byte = u8::from_str_radix(hex, 16).map_err(|_| InvalidKey)?;
+        }
+        Ok(Self(key))
+    }
+}
+
+impl Key {
+    /// Generate a new random key
+    #[must_use]
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    /// Load key from the environment (`AOC_INPUT_KEY`) or from a key file
+    /// (`AOC_INPUT_KEY_FILE`, defaults to `.input-key`). A missing key fails
+    /// with [`NoKey`] (not [`io::ErrorKind::NotFound`], so that it isn't
+    /// mistaken for a missing input).
+    ///
+    /// # Errors
+    /// I/O error, no key or invalid key
+    pub fn load() -> io::Result<Self> {
+        Self::load_from(std::env::var(KEY_VAR).ok(), &Self::path())
+    }
+
+    /// Load key from the given hex string, or from the given key file if
+    /// it's unset or empty
+    fn load_from(hex: Option<String>, path: &Path) -> io::Result<Self> {
+        let hex = match hex {
+            Some(hex) if !hex.trim().is_empty() => hex,
+            _ => match fs::read_to_string(path) {
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    return Err(io::Error::other(NoKey(path.into())));
+                }
+                res => res?,
+            },
+        };
+        hex.parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Path to the key file
+    #[must_use]
+    pub fn path() -> PathBuf {
+        std::env::var_os(KEY_FILE_VAR).map_or_else(|| KEY_FILE.into(), PathBuf::from)
+    }
+
+    /// Hex representation of this key
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        self.0.iter().fold(String::new(), |mut s, byte| {
+            let _ = write!(s, "{byte:02x}");
+            s
+        })
+    }
+
+    /// Encrypt the given data
+    ///
+    /// # Panics
+    /// Data is too large to be encrypted
+    #[must_use]
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = ChaCha20Poly1305::new(&self.0)
+            .encrypt(&nonce, plaintext)
+            .expect("encryption failed");
+        let mut data = nonce.to_vec();
+        data.extend(ciphertext);
+        data
+    }
+
+    /// Decrypt the given data
+    ///
+    /// # Errors
+    /// Data is corrupted or was encrypted with a different key
+    pub fn decrypt(&self, data: &[u8]) -> io::Result<Vec<u8>> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Unable to decrypt input");
+        if data.len() < NONCE_LEN {
+            return Err(invalid());
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        ChaCha20Poly1305::new(&self.0)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| invalid())
+    }
+}
+
+/// Whether the given error is caused by a missing input key
+pub(crate) fn is_no_key(err: &io::Error) -> bool {
+    matches!(err.get_ref(), Some(e) if e.is::<NoKey>())
+}
+
+/// Path of the encrypted file for the given plain text file
+pub(crate) fn encrypted_path(path: &Path) -> PathBuf {
+    let mut path = OsString::from(path);
+    path.push(".");
+    path.push(EXTENSION);
+    path.into()
+}
+
+/// Encrypt all plain text inputs in the input directory, returns paths of
+/// encrypted files. If `remove_plaintext` is set, plain text inputs are
+/// removed once their encrypted file decrypts to the same contents.
+///
+/// # Errors
+/// I/O error or decryption failure
+pub fn encrypt_inputs(key: &Key, remove_plaintext: bool) -> io::Result<Vec<PathBuf>> {
+    find_files(Path::new(INPUT_PATH), "txt")?
+        .into_iter()
+        .map(|path| {
+            let plaintext = fs::read(&path)?;
+            let encrypted = encrypted_path(&path);
+            fs::write(&encrypted, key.encrypt(&plaintext))?;
+            if remove_plaintext {
+                if key.decrypt(&fs::read(&encrypted)?)? != plaintext {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("Encrypted input {} doesn't match", encrypted.display()),
+                    ));
+                }
+                fs::remove_file(&path)?;
+            }
+            Ok(encrypted)
+        })
+        .collect()
+}
+
+/// Decrypt all encrypted inputs in the input directory, returns paths of
+/// decrypted files
+///
+/// # Errors
+/// I/O error or decryption failure
+pub fn decrypt_inputs(key: &Key) -> io::Result<Vec<PathBuf>> {
+    find_files(Path::new(INPUT_PATH), EXTENSION)?
+        .into_iter()
+        .map(|path| {
+            let decrypted = path.with_extension("");
+            fs::write(&decrypted, key.decrypt(&fs::read(&path)?)?)?;
+            Ok(decrypted)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn parse_key() {
+        let key: Key = KEY.parse().unwrap();
+        assert_eq!(key.to_hex(), KEY);
+        assert!("0011".parse::<Key>().is_err());
+        assert!(KEY.replace('a', "x").parse::<Key>().is_err());
+    }
+
+    #[test]
+    fn load_key() {
+        let path = Path::new("/nonexistent/.input-key");
+        let key = Key::load_from(Some(KEY.to_string()), path).unwrap();
+        assert_eq!(key.to_hex(), KEY);
+        for hex in [None, Some(String::new())] {
+            let err = Key::load_from(hex, path).err().unwrap();
+            assert_ne!(err.kind(), io::ErrorKind::NotFound);
+            assert!(is_no_key(&err));
+        }
+    }
+
+    #[test]
+    fn encrypt_decrypt() {
+        let key: Key = KEY.parse().unwrap();
+        let data = key.encrypt(b"1000\n2000\n");
+        assert_eq!(key.decrypt(&data).unwrap(), b"1000\n2000\n");
+        assert!(Key::generate().decrypt(&data).is_err());
+        assert!(key.decrypt(&data[..NONCE_LEN]).is_err());
+    }
+
+    #[test]
+    fn encrypted_file_path() {
+        let path = encrypted_path(Path::new("input/2022/day07.txt"));
+        assert_eq!(path, Path::new("input/2022/day07.txt.enc"));
+        assert_eq!(path.with_extension(""), Path::new("input/2022/day07.txt"));
+    }
+}
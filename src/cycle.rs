@@ -0,0 +1,201 @@
+//! Advent of Code: cycle detection in simulations
+//!
+//! Simulations that would take too many steps can often skip ahead once the
+//! state repeats: the state at step N equals the state at the equivalent
+//! step within the first cycle, and metrics growing by the same amount each
+//! cycle can be extrapolated.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Simulation stepping its state forward one unit at a time
+pub trait Simulation {
+    /// Part of the simulation's state that determines all future steps,
+    /// e.g. positions relative to each other rather than absolute positions
+    type State: Eq + Hash;
+
+    /// Current state
+    fn state(&self) -> Self::State;
+
+    /// Advance the simulation by one step
+    fn step(&mut self);
+}
+
+/// Cycle of states, repeating from step `start` every `len` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// First step of the cycle
+    pub start: usize,
+    /// Number of steps until the state repeats
+    pub len: usize,
+}
+
+impl Cycle {
+    /// Earliest step with the same state as the given step
+    #[must_use]
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.len
+        }
+    }
+}
+
+/// Find the cycle of the given simulation by hashing its states. Steps the
+/// simulation until its state repeats, i.e. until step `start + len`. Never
+/// returns if the simulation doesn't repeat.
+pub fn find<S: Simulation>(simulation: &mut S) -> Cycle {
+    let mut seen = HashMap::new();
+    for step in 0.. {
+        if let Some(start) = seen.insert(simulation.state(), step) {
+            return Cycle {
+                start,
+                len: step - start,
+            };
+        }
+        simulation.step();
+    }
+    unreachable!()
+}
+
+/// Find the cycle of iterating the given function from the given value with
+/// Brent's algorithm, which compares values instead of storing them
+pub fn brent<T, F>(initial: T, f: F) -> Cycle
+where
+    T: Clone + PartialEq,
+    F: Fn(&T) -> T,
+{
+    // Find the cycle length by searching in windows of growing powers of 2
+    let (mut power, mut len) = (1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = f(&initial);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = f(&hare);
+        len += 1;
+    }
+    // Find the cycle start with the hare `len` steps ahead
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..len {
+        hare = f(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+    Cycle { start, len }
+}
+
+/// Advance the given simulation by the given number of steps, skipping
+/// whole cycles once its state repeats
+pub fn run<S: Simulation>(simulation: &mut S, steps: usize) {
+    extrapolate(simulation, steps, |_| 0);
+}
+
+/// Advance the given simulation by the given number of steps like [`run`]
+/// and return the metric at the last step. The metric must grow by the same
+/// amount during each cycle, e.g. the height of a tower of rocks.
+pub fn extrapolate<S, F>(simulation: &mut S, steps: usize, mut metric: F) -> i128
+where
+    S: Simulation,
+    F: FnMut(&S) -> i128,
+{
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    for step in 0..steps {
+        history.push(metric(simulation));
+        if let Some(start) = seen.insert(simulation.state(), step) {
+            let len = step - start;
+            let (cycles, rest) = ((steps - step) / len, (steps - step) % len);
+            for _ in 0..rest {
+                simulation.step();
+            }
+            let growth = history[step] - history[start];
+            return history[start + rest] + (cycles + 1) as i128 * growth;
+        }
+        simulation.step();
+    }
+    metric(simulation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counter running from 0 to 9 and then wrapping around to 3, with the
+    /// total of all values counted so far
+    struct Counter {
+        value: u32,
+        total: i128,
+    }
+
+    impl Simulation for Counter {
+        type State = u32;
+
+        fn state(&self) -> u32 {
+            self.value
+        }
+
+        fn step(&mut self) {
+            self.value = if self.value == 9 { 3 } else { self.value + 1 };
+            self.total += i128::from(self.value);
+        }
+    }
+
+    fn counter() -> Counter {
+        Counter { value: 0, total: 0 }
+    }
+
+    fn brute_force(steps: usize) -> Counter {
+        let mut counter = counter();
+        for _ in 0..steps {
+            counter.step();
+        }
+        counter
+    }
+
+    #[test]
+    fn find_cycle() {
+        let mut counter = counter();
+        let cycle = find(&mut counter);
+        assert_eq!(cycle, Cycle { start: 3, len: 7 });
+        assert_eq!(counter.value, 3);
+        assert_eq!(cycle.reduce(2), 2);
+        assert_eq!(cycle.reduce(10), 3);
+        assert_eq!(cycle.reduce(1_000_000), 3 + (1_000_000 - 3) % 7);
+    }
+
+    #[test]
+    fn brent_cycle() {
+        let cycle = brent(0, |&value| if value == 9 { 3 } else { value + 1 });
+        assert_eq!(cycle, Cycle { start: 3, len: 7 });
+        assert_eq!(brent(0, |_| 0), Cycle { start: 0, len: 1 });
+    }
+
+    #[test]
+    fn run_and_extrapolate() {
+        for steps in [0, 1, 5, 10, 11, 17, 100] {
+            let expected = brute_force(steps);
+            let mut counter = counter();
+            let total = extrapolate(&mut counter, steps, |counter| counter.total);
+            assert_eq!(total, expected.total, "{steps} steps");
+            assert_eq!(counter.value, expected.value, "{steps} steps");
+        }
+        let mut counter = counter();
+        run(&mut counter, 1_000_000_000_000);
+        assert_eq!(
+            counter.value,
+            brute_force(3 + (1_000_000_000_000 - 3) % 7).value
+        );
+        let total = extrapolate(&mut self::counter(), 1_000_000_000_000, |c| c.total);
+        assert_eq!(total, 6_000_000_000_000 - 12);
+    }
+}
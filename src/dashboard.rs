@@ -0,0 +1,769 @@
+//! Advent of Code: interactive dashboard
+//!
+//! Terminal UI listing all days of a year with their status, last answers
+//! and timings. Days are run with the [`runner`](crate::runner) on the
+//! puzzle input or one of the example inputs, visualizations are played
+//! inline. The terminal is switched to unbuffered input with `stty`, so this
+//! requires a Unix-like terminal.
+
+use crate::error::describe;
+use crate::runner::{PartRun, Run};
+use crate::visualize::Frame;
+use crate::{registry, Answer, Answers, Error, Puzzle, Result};
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io::{self, IsTerminal, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// Number of days of an event
+const DAYS: usize = 25;
+
+/// Width of an answer column in the list of days
+const ANSWER_WIDTH: usize = 20;
+
+/// Fastest playback speed of visualizations, in frames per tick
+const MAX_SPEED: usize = 1024;
+
+/// Key pressed by the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    Enter,
+    Tab,
+    Esc,
+    Interrupt,
+    Char(char),
+}
+
+impl Key {
+    /// Key of an escape sequence with the given final byte
+    fn from_final_byte(code: u8) -> Option<Self> {
+        match code {
+            b'A' => Some(Self::Up),
+            b'B' => Some(Self::Down),
+            b'C' => Some(Self::Right),
+            b'D' => Some(Self::Left),
+            b'H' => Some(Self::Home),
+            b'F' => Some(Self::End),
+            _ => None,
+        }
+    }
+
+    /// Parse keys from bytes read from the terminal. Unknown escape
+    /// sequences and control characters are ignored.
+    fn parse_all(bytes: &[u8]) -> Vec<Self> {
+        let mut keys = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let (key, len) = match &bytes[i..] {
+                [0x1b, b'[', rest @ ..] => {
+                    // Control sequence: parameter and intermediate bytes up
+                    // to a final byte. Incomplete sequences are dropped.
+                    let len = rest
+                        .iter()
+                        .position(|b| (0x40..=0x7e).contains(b))
+                        .map_or(rest.len(), |end| end + 1);
+                    let key = match &rest[..len] {
+                        [b'1' | b'7', b'~'] => Some(Self::Home),
+                        [b'4' | b'8', b'~'] => Some(Self::End),
+                        [.., code] => Self::from_final_byte(*code),
+                        [] => None,
+                    };
+                    (key, 2 + len)
+                }
+                [0x1b, b'O', code, ..] => (Self::from_final_byte(*code), 3),
+                [0x1b, ..] => (Some(Self::Esc), 1),
+                [b'\r' | b'\n', ..] => (Some(Self::Enter), 1),
+                [b'\t', ..] => (Some(Self::Tab), 1),
+                [0x03, ..] => (Some(Self::Interrupt), 1),
+                rest => {
+                    let len = match rest[0] {
+                        0xc0..=0xdf => 2,
+                        0xe0..=0xef => 3,
+                        0xf0..=0xf7 => 4,
+                        _ => 1,
+                    };
+                    let len = len.min(rest.len());
+                    let ch = std::str::from_utf8(&rest[..len])
+                        .ok()
+                        .and_then(|s| s.chars().next())
+                        .filter(|ch| !ch.is_control());
+                    (ch.map(Self::Char), len)
+                }
+            };
+            keys.extend(key);
+            i += len;
+        }
+        keys
+    }
+}
+
+/// Input a day is run with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    /// Puzzle input
+    Input,
+    /// Example input with the given index
+    Example(usize),
+}
+
+/// Status of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    /// There's no solution for the day
+    NotImplemented,
+    /// The day wasn't run yet
+    NotRun,
+    /// All parts were answered (correctly, if answers were recorded)
+    Solved,
+    /// Reading or parsing the input failed, or a part failed or gave a
+    /// wrong answer
+    Failing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::NotImplemented => "not implemented",
+            Self::NotRun => "not run",
+            Self::Solved => "solved",
+            Self::Failing => "failing",
+        })
+    }
+}
+
+/// Day of the event with its last run
+#[derive(Debug)]
+struct Day {
+    number: usize,
+    puzzle: Option<Puzzle>,
+    examples: Vec<PathBuf>,
+    source: Source,
+    run: Option<Result<Run>>,
+}
+
+impl Day {
+    /// Name of the input the day is run with
+    fn source_name(&self) -> String {
+        match self.source {
+            Source::Input => "input".to_string(),
+            Source::Example(i) => format!("example {}", i + 1),
+        }
+    }
+
+    /// Switch to the next input, cycling through the puzzle input and all
+    /// examples. Results of the previous input are discarded.
+    fn next_source(&mut self) {
+        self.source = match self.source {
+            Source::Input if !self.examples.is_empty() => Source::Example(0),
+            Source::Example(i) if i + 1 < self.examples.len() => Source::Example(i + 1),
+            _ => Source::Input,
+        };
+        self.run = None;
+    }
+
+    /// Read the input the day is run with
+    fn input(&self, puzzle: &Puzzle) -> Result<String> {
+        match self.source {
+            Source::Input => Ok(puzzle.input()?.into_string()?),
+            Source::Example(i) => Ok(fs::read_to_string(&self.examples[i])?),
+        }
+    }
+
+    /// Record the result of running the day. A run of a single part only
+    /// replaces the answer of that part.
+    fn record(&mut self, run: Result<Run>, single_part: bool) {
+        match (&mut self.run, run) {
+            (Some(Ok(last)), Ok(run)) if single_part => {
+                last.parse_duration = run.parse_duration;
+                for part in run.parts {
+                    last.parts.retain(|p| p.part != part.part);
+                    last.parts.push(part);
+                }
+                last.parts.sort_by_key(|p| p.part);
+            }
+            (last, run) => *last = Some(run),
+        }
+    }
+
+    /// Recorded answer the answer of the given part doesn't match. Answers
+    /// are only checked if the day was run with the puzzle input.
+    fn wrong_answer<'a>(&self, part: &PartRun, answers: &'a Answers) -> Option<&'a Answer> {
+        let (Some(puzzle), Source::Input, Ok(answer)) = (self.puzzle, self.source, &part.answer)
+        else {
+            return None;
+        };
+        answers
+            .get(puzzle.year, puzzle.day, part.part)
+            .filter(|expected| !answer.matches(expected))
+    }
+
+    /// Status of the day based on its last run and the recorded answers
+    fn status(&self, answers: &Answers) -> Status {
+        let is_correct =
+            |part: &PartRun| part.answer.is_ok() && self.wrong_answer(part, answers).is_none();
+        match &self.run {
+            _ if self.puzzle.is_none() => Status::NotImplemented,
+            None => Status::NotRun,
+            Some(Ok(run)) if run.parts.iter().all(is_correct) => Status::Solved,
+            Some(_) => Status::Failing,
+        }
+    }
+
+    /// Short form of the last answer to the given part
+    fn answer_summary(&self, part: u8) -> String {
+        let Some(Ok(run)) = &self.run else {
+            return String::new();
+        };
+        match run.parts.iter().find(|p| p.part == part).map(|p| &p.answer) {
+            Some(Ok(answer)) if answer.to_string().contains('\n') => "(image)".to_string(),
+            Some(Ok(answer)) => answer.to_string(),
+            Some(Err(_)) => "error".to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// Details of the last run, one entry per line
+    fn details(&self, answers: &Answers) -> Vec<String> {
+        let mut lines = vec![format!("Day {} with {}", self.number, self.source_name())];
+        match &self.run {
+            _ if self.puzzle.is_none() => lines.push("  No solution yet".to_string()),
+            None => lines.push("  Not run yet, press enter to run".to_string()),
+            Some(Err(e)) => lines.push(format!("  Error: {}", describe(e))),
+            Some(Ok(run)) => {
+                lines.push(format!("  Parsed in {:.2?}", run.parse_duration));
+                for part in &run.parts {
+                    let (n, duration) = (part.part, part.duration);
+                    let answer = match &part.answer {
+                        Ok(answer) => answer.to_string(),
+                        Err(e) => {
+                            lines.push(format!("  Part {n}: Error: {}", describe(e)));
+                            continue;
+                        }
+                    };
+                    if let Some(expected) = self.wrong_answer(part, answers) {
+                        lines.push(format!(
+                            "  Part {n}: {answer} ({duration:.2?}), expected {expected}"
+                        ));
+                    } else if answer.contains('\n') {
+                        lines.push(format!("  Part {n} ({duration:.2?}):"));
+                        lines.extend(answer.lines().map(|line| format!("  {line}")));
+                    } else {
+                        lines.push(format!("  Part {n}: {answer} ({duration:.2?})"));
+                    }
+                }
+            }
+        }
+        lines
+    }
+}
+
+/// What the dashboard shows
+#[derive(Debug)]
+enum View {
+    /// List of days with details of the selected day
+    Days,
+    /// Visualization of the selected day, playing or paused at a frame
+    Visualization {
+        frames: Vec<Frame>,
+        frame: usize,
+        speed: usize,
+        playing: bool,
+    },
+}
+
+/// Interactive dashboard of all days of a year
+#[derive(Debug)]
+pub struct Dashboard {
+    year: u16,
+    answers: Answers,
+    days: Vec<Day>,
+    selected: usize,
+    view: View,
+    message: Option<String>,
+    done: bool,
+}
+
+impl Dashboard {
+    /// Create dashboard of the given year, checking answers against the
+    /// given recorded answers
+    #[must_use]
+    pub fn new(year: u16, answers: Answers) -> Self {
+        let days = (1..=DAYS)
+            .map(|day| {
+                let puzzle = registry::find(year, day).copied();
+                let examples = puzzle.and_then(|p| p.examples().ok());
+                Day {
+                    number: day,
+                    puzzle,
+                    examples: examples.unwrap_or_default(),
+                    source: Source::Input,
+                    run: None,
+                }
+            })
+            .collect();
+        Self {
+            year,
+            answers,
+            days,
+            selected: 0,
+            view: View::Days,
+            message: None,
+            done: false,
+        }
+    }
+
+    /// Run the given day, or only the given part of it
+    fn run_day(&mut self, index: usize, part: Option<u8>) {
+        let day = &mut self.days[index];
+        let Some(puzzle) = day.puzzle else {
+            return;
+        };
+        let run = day.input(&puzzle).and_then(|input| {
+            guarded(|| match part {
+                Some(part) => Run::part(&puzzle, &input, part),
+                None => Run::new(&puzzle, &input),
+            })
+        });
+        day.record(run, part.is_some());
+    }
+
+    /// Collect the frames of visualizing the selected day and show them
+    fn visualize(&mut self) {
+        let day = &self.days[self.selected];
+        let Some(puzzle) = day.puzzle else {
+            self.message = Some(format!("Day {} has no solution", day.number));
+            return;
+        };
+        let frames = day.input(&puzzle).and_then(|input| {
+            guarded(|| {
+                let mut frames = Vec::new();
+                let res = puzzle.parse(&input)?.visualize(&mut frames);
+                res.map(|res| res.map(|()| frames)).transpose()
+            })
+        });
+        match frames {
+            Ok(Some(frames)) if !frames.is_empty() => {
+                self.view = View::Visualization {
+                    frames,
+                    frame: 0,
+                    speed: 1,
+                    playing: true,
+                };
+            }
+            Ok(_) => self.message = Some(format!("Day {} has no visualization", day.number)),
+            Err(e) => self.message = Some(format!("Error: {}", describe(&e))),
+        }
+    }
+
+    /// Handle a key pressed by the user
+    fn handle(&mut self, key: Key) {
+        self.message = None;
+        if key == Key::Interrupt {
+            self.done = true;
+            return;
+        }
+        match &mut self.view {
+            View::Days => match key {
+                Key::Up | Key::Char('k') => self.selected = self.selected.saturating_sub(1),
+                Key::Down | Key::Char('j') => {
+                    self.selected = (self.selected + 1).min(self.days.len() - 1);
+                }
+                Key::Home => self.selected = 0,
+                Key::End => self.selected = self.days.len() - 1,
+                Key::Enter | Key::Char('r') => self.run_day(self.selected, None),
+                Key::Char('1') => self.run_day(self.selected, Some(1)),
+                Key::Char('2') => self.run_day(self.selected, Some(2)),
+                Key::Char('a') => {
+                    for index in 0..self.days.len() {
+                        self.run_day(index, None);
+                    }
+                }
+                Key::Tab | Key::Char('i') => self.days[self.selected].next_source(),
+                Key::Char('v') => self.visualize(),
+                Key::Esc | Key::Char('q') => self.done = true,
+                _ => (),
+            },
+            View::Visualization {
+                frames,
+                frame,
+                speed,
+                playing,
+            } => match key {
+                Key::Left | Key::Char('h') => {
+                    *frame = frame.saturating_sub(1);
+                    *playing = false;
+                }
+                Key::Right | Key::Char('l') => {
+                    *frame = (*frame + 1).min(frames.len() - 1);
+                    *playing = false;
+                }
+                Key::Home => *frame = 0,
+                Key::End => *frame = frames.len() - 1,
+                Key::Char(' ') => *playing = !*playing,
+                Key::Char('+') => *speed = (*speed * 2).min(MAX_SPEED),
+                Key::Char('-') => *speed = (*speed / 2).max(1),
+                Key::Esc | Key::Char('q') => self.view = View::Days,
+                _ => (),
+            },
+        }
+    }
+
+    /// Advance a playing visualization
+    fn tick(&mut self) {
+        if let View::Visualization {
+            frames,
+            frame,
+            speed,
+            playing: playing @ true,
+        } = &mut self.view
+        {
+            *frame = (*frame + *speed).min(frames.len() - 1);
+            *playing = *frame + 1 < frames.len();
+        }
+    }
+
+    /// Render the screen of the given size, one line per row
+    fn render(&self, width: usize, height: usize) -> String {
+        let (mut lines, help) = match &self.view {
+            View::Days => (
+                self.render_days(height),
+                "↑/↓ select  enter run  1/2 run part  a run all  i switch input  v visualize  q quit",
+            ),
+            View::Visualization {
+                frames,
+                frame,
+                speed,
+                playing,
+            } => {
+                let day = &self.days[self.selected];
+                let mut lines = vec![format!(
+                    "Day {} visualization with {}: frame {}/{}, {speed}x{}",
+                    day.number,
+                    day.source_name(),
+                    frame + 1,
+                    frames.len(),
+                    if *playing { ", playing" } else { "" }
+                )];
+                lines.extend(frames[*frame].to_string().lines().map(String::from));
+                (
+                    lines,
+                    "←/→ step  space play/pause  +/- speed  home/end first/last  esc back",
+                )
+            }
+        };
+        lines.truncate(height.saturating_sub(1));
+        lines.resize(height.saturating_sub(1), String::new());
+        lines.push(self.message.as_deref().unwrap_or(help).to_string());
+        lines
+            .iter()
+            .map(|line| line.chars().take(width).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Render the list of days and details of the selected day. The list
+    /// scrolls to the selected day if the given height leaves room for less
+    /// than 3 lines of details.
+    fn render_days(&self, height: usize) -> Vec<String> {
+        let mut lines = vec![
+            format!("Advent of Code {}", self.year),
+            String::new(),
+            format!(
+                "  Day  {:<15}  {:<10}  {:<ANSWER_WIDTH$}  {:<ANSWER_WIDTH$}  {:>10}",
+                "Status", "Input", "Part 1", "Part 2", "Time"
+            ),
+        ];
+        let rows = self.days.len().min(height.saturating_sub(8).max(1));
+        let first = (self.selected + 1).saturating_sub(rows);
+        for (index, day) in self.days.iter().enumerate().skip(first).take(rows) {
+            let time = match &day.run {
+                Some(Ok(run)) => format!("{:.2?}", run.duration()),
+                _ => String::new(),
+            };
+            let mut row = String::new();
+            let _ = write!(
+                row,
+                "{} {:>3}  {:<15}  {:<10}  {:<ANSWER_WIDTH$}  {:<ANSWER_WIDTH$}  {time:>10}",
+                if index == self.selected { '>' } else { ' ' },
+                day.number,
+                day.status(&self.answers),
+                day.source_name(),
+                shorten(&day.answer_summary(1), ANSWER_WIDTH),
+                shorten(&day.answer_summary(2), ANSWER_WIDTH),
+            );
+            lines.push(row);
+        }
+        lines.push(String::new());
+        lines.extend(self.days[self.selected].details(&self.answers));
+        lines
+    }
+
+    /// Run the dashboard in the terminal until the user quits
+    ///
+    /// # Errors
+    /// I/O error, e.g. if standard input isn't a terminal
+    pub fn run(mut self) -> io::Result<()> {
+        if !io::stdin().is_terminal() {
+            return Err(io::Error::other("dashboard requires a terminal"));
+        }
+        let raw_mode = RawMode::enable()?;
+        let mut out = io::stdout().lock();
+        // Switch to alternate screen and hide cursor
+        write!(out, "\x1b[?1049h\x1b[?25l")?;
+        // Panics are reported as errors of the run, don't print them
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let res = self.run_loop(&mut out);
+        panic::set_hook(hook);
+        // Show cursor and switch back to main screen
+        write!(out, "\x1b[?25h\x1b[?1049l")?;
+        out.flush()?;
+        drop(raw_mode);
+        res
+    }
+
+    /// Draw the screen and handle keys until the user quits. Reading keys
+    /// times out regularly to advance visualizations.
+    fn run_loop(&mut self, out: &mut impl Write) -> io::Result<()> {
+        let mut stdin = io::stdin().lock();
+        let mut buf = [0; 64];
+        let mut last_screen = String::new();
+        while !self.done {
+            let (width, height) = terminal_size();
+            let screen = self.render(width, height);
+            if screen != last_screen {
+                // Move cursor to top left, clear rest of each line and
+                // everything below
+                write!(
+                    out,
+                    "\x1b[H{}\x1b[K\x1b[J",
+                    screen.replace('\n', "\x1b[K\n")
+                )?;
+                out.flush()?;
+                last_screen = screen;
+            }
+            match stdin.read(&mut buf)? {
+                0 => self.tick(),
+                n => Key::parse_all(&buf[..n])
+                    .into_iter()
+                    .for_each(|key| self.handle(key)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Run the given function, turning a panic into an error
+fn guarded<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|_| Err(Error::invalid_state("solver panicked")))
+}
+
+/// Shorten the given text to the given number of characters
+fn shorten(s: &str, width: usize) -> String {
+    if s.chars().count() <= width {
+        return s.to_string();
+    }
+    s.chars().take(width - 1).chain(Some('…')).collect()
+}
+
+/// Run `stty` with the given arguments on the terminal, returning its output
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(format!("stty failed: {}", message.trim())));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Size of the terminal (columns and rows), or 80x24 if it's unknown
+fn terminal_size() -> (usize, usize) {
+    stty(&["size"])
+        .ok()
+        .and_then(|size| {
+            let (rows, columns) = size.split_once(' ')?;
+            Some((columns.parse().ok()?, rows.parse().ok()?))
+        })
+        .filter(|&(columns, rows)| columns > 0 && rows > 0)
+        .unwrap_or((80, 24))
+}
+
+/// Terminal mode without line buffering and echo, where reading input times
+/// out after 0.1 s. The previous mode is restored when dropped.
+#[derive(Debug)]
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "0", "time", "1"])?;
+        Ok(Self { saved })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CALORIES: &str = "1000\n2000\n\n4000\n";
+
+    fn dashboard() -> Dashboard {
+        Dashboard::new(2022, Answers::default())
+    }
+
+    fn run(day: usize, input: &str) -> Result<Run> {
+        Run::new(registry::find(2022, day).unwrap(), input)
+    }
+
+    #[test]
+    fn parse_keys() {
+        assert_eq!(
+            Key::parse_all(b"\x1b[A\x1b[Bj\r\x1bOC\x1b[1~\x1b[4~\x1b"),
+            [
+                Key::Up,
+                Key::Down,
+                Key::Char('j'),
+                Key::Enter,
+                Key::Right,
+                Key::Home,
+                Key::End,
+                Key::Esc
+            ]
+        );
+        assert_eq!(
+            Key::parse_all("\x1b[Z\x1b[5~ä\t\x03\x7f".as_bytes()),
+            [Key::Char('ä'), Key::Tab, Key::Interrupt]
+        );
+        assert_eq!(
+            Key::parse_all(b"\x1b[15~1\x1b[24~\x1b[1;5A\x1b[2"),
+            [Key::Char('1'), Key::Up]
+        );
+    }
+
+    #[test]
+    fn statuses() {
+        let mut dashboard = dashboard();
+        let day = &mut dashboard.days[0];
+        assert_eq!(day.status(&dashboard.answers), Status::NotRun);
+        day.record(run(1, CALORIES), false);
+        assert_eq!(day.status(&dashboard.answers), Status::Solved);
+        assert_eq!(day.answer_summary(1), "4000");
+        dashboard.answers.insert(2022, 1, 2, 7001_u32.into());
+        assert_eq!(day.status(&dashboard.answers), Status::Failing);
+        assert!(day.details(&dashboard.answers)[3].ends_with(", expected 7001"));
+        day.record(run(1, "x\n"), false);
+        assert_eq!(day.status(&dashboard.answers), Status::Failing);
+        assert_eq!(
+            dashboard.days[24].status(&dashboard.answers),
+            Status::NotImplemented
+        );
+    }
+
+    #[test]
+    fn rerun_part() {
+        let mut day = dashboard().days.swap_remove(0);
+        day.record(run(1, CALORIES), false);
+        let puzzle = day.puzzle.unwrap();
+        day.record(Run::part(&puzzle, "1\n\n2\n\n3\n", 2), true);
+        assert_eq!(day.answer_summary(1), "4000");
+        assert_eq!(day.answer_summary(2), "6");
+        day.next_source();
+        assert_eq!((day.source, day.run.is_none()), (Source::Input, true));
+    }
+
+    #[test]
+    fn navigate() {
+        let mut dashboard = dashboard();
+        dashboard.handle(Key::Up);
+        assert_eq!(dashboard.selected, 0);
+        dashboard.handle(Key::Down);
+        dashboard.handle(Key::Char('j'));
+        assert_eq!(dashboard.selected, 2);
+        dashboard.handle(Key::End);
+        dashboard.handle(Key::Down);
+        assert_eq!(dashboard.selected, 24);
+        dashboard.handle(Key::Char('v'));
+        assert_eq!(dashboard.message.as_deref(), Some("Day 25 has no solution"));
+        assert!(!dashboard.done);
+        dashboard.handle(Key::Char('q'));
+        assert!(dashboard.done);
+    }
+
+    #[test]
+    fn render_days() {
+        let mut dashboard = dashboard();
+        dashboard.days[0].record(run(1, CALORIES), false);
+        dashboard.days[1].record(run(2, "A X\nB Q\n"), false);
+        dashboard.selected = 1;
+        let screen = dashboard.render(100, 40);
+        let lines: Vec<_> = screen.lines().collect();
+        assert_eq!(lines.len(), 40);
+        assert_eq!(lines[0], "Advent of Code 2022");
+        assert!(lines[2].starts_with("  Day  Status           Input       Part 1"));
+        assert!(lines[3].starts_with("    1  solved           input       4000      "));
+        assert!(lines[4].starts_with(">   2  failing          input"));
+        assert!(lines[13].starts_with("   11  not implemented  input"));
+        assert_eq!(lines[29], "Day 2 with input");
+        assert!(lines[30].starts_with("  Error: Parse error at line 2"));
+        assert!(lines[39].starts_with("↑/↓ select"));
+
+        let screen = dashboard.render(30, 12);
+        let lines: Vec<_> = screen.lines().collect();
+        assert_eq!(lines.len(), 12);
+        assert!(lines.iter().all(|line| line.chars().count() <= 30));
+        assert!(lines[3].starts_with("    1  solved"));
+        assert!(lines[4].starts_with(">   2  failing"));
+        assert_eq!(lines[8], "Day 2 with input");
+    }
+
+    #[test]
+    fn play_visualization() {
+        let mut dashboard = dashboard();
+        dashboard.view = View::Visualization {
+            frames: ["#", "##", "###"].map(Frame::from).to_vec(),
+            frame: 0,
+            speed: 1,
+            playing: true,
+        };
+        let screen = dashboard.render(80, 5);
+        assert_eq!(
+            screen.lines().take(2).collect::<Vec<_>>(),
+            [
+                "Day 1 visualization with input: frame 1/3, 1x, playing",
+                "#"
+            ]
+        );
+        dashboard.tick();
+        dashboard.tick();
+        dashboard.tick();
+        assert!(dashboard
+            .render(80, 5)
+            .starts_with("Day 1 visualization with input: frame 3/3, 1x\n###"));
+        dashboard.handle(Key::Left);
+        dashboard.handle(Key::Char('+'));
+        assert!(dashboard.render(80, 5).contains("frame 2/3, 2x\n"));
+        dashboard.handle(Key::Esc);
+        assert!(matches!(dashboard.view, View::Days));
+        assert!(!dashboard.done);
+    }
+}
@@ -0,0 +1,61 @@
+//! Advent of Code: line diffs
+//!
+//! Shows how multi-line output changed, based on the longest common
+//! subsequence of lines, so that inserting or removing a line doesn't mark
+//! all following lines as changed.
+
+use itertools::Itertools;
+
+/// Compare lines of old and new text. Each line is prefixed by ` ` if it's
+/// in both texts, `-` if it's only in the old text and `+` if it's only in
+/// the new text.
+pub(crate) fn line_diff(old: &str, new: &str) -> String {
+    let old: Vec<_> = old.lines().collect();
+    let new: Vec<_> = new.lines().collect();
+    // Length of the longest common subsequence of `old[i..]` and `new[j..]`
+    let mut lcs = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push(format!(" {}", old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if j == new.len() || (i < old.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push(format!("-{}", old[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+{}", new[j]));
+            j += 1;
+        }
+    }
+    diff.into_iter().join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_lines() {
+        assert_eq!(line_diff("a\nb", "a\nb"), " a\n b");
+        assert_eq!(line_diff("#.\n.#", "#.\n##\n.."), " #.\n-.#\n+##\n+..");
+        assert_eq!(line_diff("a\nb\nc", "a"), " a\n-b\n-c");
+        assert_eq!(line_diff("", "a"), "+a");
+    }
+
+    #[test]
+    fn diff_inserted_and_removed_lines() {
+        assert_eq!(line_diff("a\nb\nc", "a\nx\nb\nc"), " a\n+x\n b\n c");
+        assert_eq!(line_diff("a\nb\nc\nd", "a\nc\nd"), " a\n-b\n c\n d");
+        assert_eq!(line_diff("a\nb\nc", "x\nb\ny"), "-a\n+x\n b\n-c\n+y");
+    }
+}
@@ -0,0 +1,191 @@
+//! Advent of Code: differential testing of alternative implementations
+//!
+//! A naive implementation of a puzzle part is easy to get right, an
+//! optimized one is easy to get wrong. A [`Harness`] runs all registered
+//! implementations of a part on the same inputs and reports the first input
+//! they disagree on.
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::Puzzle;
+use crate::{Answer, Context, Error, Result};
+use std::fmt;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+#[cfg(not(target_arch = "wasm32"))]
+use std::io;
+use std::ops::Range;
+
+/// Type-erased implementation of a puzzle part
+type SolveFn<'a> = Box<dyn Fn(&str) -> Result<Answer> + 'a>;
+
+/// Alternative implementations of a puzzle part, expected to give the same
+/// answers on all (valid) inputs
+#[derive(Default)]
+pub struct Harness<'a> {
+    implementations: Vec<(String, SolveFn<'a>)>,
+}
+
+impl fmt::Debug for Harness<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self.implementations.iter().map(|(name, _)| name);
+        f.debug_struct("Harness")
+            .field("implementations", &names.collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<'a> Harness<'a> {
+    /// Create harness without implementations
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an implementation solving the part for the given input. The
+    /// first registered implementation is the reference for all others.
+    #[must_use]
+    pub fn register<A, F>(mut self, name: &str, solve: F) -> Self
+    where
+        A: Into<Answer>,
+        F: Fn(&str) -> Result<A> + 'a,
+    {
+        let solve = move |input: &str| solve(input).map(Into::into);
+        self.implementations
+            .push((name.to_string(), Box::new(solve)));
+        self
+    }
+
+    /// Check that all implementations give the same answer for the given
+    /// input, described by the given case name in errors
+    ///
+    /// # Errors
+    /// Error of any implementation or wrong answer compared to the reference
+    pub fn check(&self, case: &str, input: &str) -> Result<()> {
+        let mut implementations = self.implementations.iter();
+        let Some((reference_name, reference)) = implementations.next() else {
+            return Ok(());
+        };
+        let context = |name: &str| format!("{case}: {name}");
+        let expected = reference(input).with_context(|| context(reference_name))?;
+        for (name, solve) in implementations {
+            let actual = solve(input).with_context(|| context(name))?;
+            if !actual.matches(&expected) {
+                let err = Error::WrongAnswer {
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                };
+                return Err(err.context(format!("{case}: {name} differs from {reference_name}")));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check all implementations with the examples and the input of the
+    /// given puzzle, as far as they exist. Returns the number of checked
+    /// inputs.
+    ///
+    /// # Errors
+    /// I/O error, error of any implementation or wrong answer
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn check_puzzle(&self, puzzle: &Puzzle) -> Result<usize> {
+        let mut checked = 0;
+        for path in puzzle.examples()? {
+            let input = fs::read_to_string(&path)?;
+            self.check(&format!("example {}", path.display()), &input)?;
+            checked += 1;
+        }
+        match puzzle.input() {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+            res => {
+                self.check("puzzle input", &res?.into_string()?)?;
+                checked += 1;
+            }
+        }
+        Ok(checked)
+    }
+
+    /// Check all implementations with inputs created by the given generator
+    /// for each of the given seeds
+    ///
+    /// # Errors
+    /// Error of any implementation or wrong answer
+    pub fn check_generated<G>(&self, seeds: Range<u64>, mut generate: G) -> Result<()>
+    where
+        G: FnMut(u64) -> String,
+    {
+        for seed in seeds {
+            self.check(&format!("generated input (seed {seed})"), &generate(seed))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum(input: &str) -> Result<u64> {
+        input
+            .split_whitespace()
+            .map(|n| Ok(n.parse::<u64>()?))
+            .sum()
+    }
+
+    /// Sum with a bug for inputs with more than 3 numbers
+    fn buggy_sum(input: &str) -> Result<u64> {
+        Ok(sum(input)? + u64::from(input.split_whitespace().count() > 3))
+    }
+
+    #[test]
+    fn agreeing_implementations() {
+        let harness = Harness::new()
+            .register("sum", sum)
+            .register("fold", |input| {
+                let mut numbers = input.split_whitespace().map(str::parse::<u64>);
+                numbers.try_fold(0, |sum, n| Ok(sum + n?))
+            });
+        assert!(harness.check("example", "1 2 3").is_ok());
+        assert!(harness
+            .check_generated(0..50, |seed| format!("{seed} {}", seed * 7))
+            .is_ok());
+    }
+
+    #[test]
+    fn first_divergence() {
+        let harness = Harness::new()
+            .register("sum", sum)
+            .register("buggy", buggy_sum);
+        let err = harness
+            .check_generated(1..10, |seed| (0..seed).map(|_| "1 ").collect())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "generated input (seed 4): buggy differs from sum"
+        );
+        let Error::Context { source, .. } = err else {
+            panic!("expected error with context");
+        };
+        assert_eq!(source.to_string(), "Wrong answer 5 (expected 4)");
+        let err = harness.check("example", "1 x").unwrap_err();
+        assert_eq!(err.to_string(), "example: sum");
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn puzzle_inputs() {
+        let puzzle = crate::registry::find(2022, 1).unwrap();
+        // Real inputs are encrypted, skip without input key (e.g. in pull
+        // requests from forks)
+        if matches!(puzzle.input(), Err(e) if crate::crypt::is_no_key(&e)) {
+            return;
+        }
+        let harness = Harness::new().register("solver", |input| {
+            puzzle
+                .parse(input)?
+                .solve(1)
+                .unwrap_or(Ok(Answer::Integer(0)))
+        });
+        assert_eq!(harness.check_puzzle(puzzle).unwrap(), 1);
+        assert_eq!(Harness::new().check_puzzle(puzzle).unwrap(), 1);
+    }
+}
@@ -0,0 +1,209 @@
+//! Advent of Code: error handling
+
+use std::fmt::{self, Write};
+use std::{io, num::ParseIntError};
+use thiserror::Error;
+
+/// Puzzle solving error
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Reading puzzle input failed
+    #[error("I/O error: {0}")]
+    Io(io::Error),
+    /// Puzzle input is malformed, optionally at the given line (starting at 1)
+    #[error("Parse error{}: {reason}", .line.map_or(String::new(), |line| format!(" at line {line}")))]
+    Parse { line: Option<usize>, reason: String },
+    /// Puzzle reached a state that shouldn't be possible
+    #[error("Invalid puzzle state: {0}")]
+    InvalidState(String),
+    /// Puzzle has no solution
+    #[error("Unsolvable puzzle: {0}")]
+    Unsolvable(String),
+    /// Answer doesn't match the recorded answer
+    #[error("Wrong answer {actual} (expected {expected})")]
+    WrongAnswer { expected: String, actual: String },
+    /// Several errors, e.g. of all invalid lines of an input
+    #[error("{} errors:{}", .0.len(), indented_lines(.0))]
+    Multiple(Vec<Error>),
+    /// Error with additional context
+    #[error("{context}")]
+    Context {
+        context: String,
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Self::parse(err)
+    }
+}
+
+impl Error {
+    /// Create parse error with the given reason
+    pub fn parse<R: fmt::Display>(reason: R) -> Self {
+        Self::Parse {
+            line: None,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Create invalid state error with the given reason
+    pub fn invalid_state<R: fmt::Display>(reason: R) -> Self {
+        Self::InvalidState(reason.to_string())
+    }
+
+    /// Create unsolvable error with the given reason
+    pub fn unsolvable<R: fmt::Display>(reason: R) -> Self {
+        Self::Unsolvable(reason.to_string())
+    }
+
+    /// Locate parse error at the given line (starting at 1), unless it's
+    /// already located
+    #[must_use]
+    pub fn at_line(self, line: usize) -> Self {
+        match self {
+            Self::Parse { line: None, reason } => Self::Parse {
+                line: Some(line),
+                reason,
+            },
+            err => err,
+        }
+    }
+
+    /// Wrap error with additional context
+    #[must_use]
+    pub fn context<C: fmt::Display>(self, context: C) -> Self {
+        Self::Context {
+            context: context.to_string(),
+            source: Box::new(self),
+        }
+    }
+}
+
+/// Format errors as indented lines
+fn indented_lines(errors: &[Error]) -> String {
+    errors.iter().fold(String::new(), |mut s, err| {
+        let _ = write!(s, "\n  {err}");
+        s
+    })
+}
+
+/// Describe the given error and its causes
+pub(crate) fn describe(err: &Error) -> String {
+    let mut message = err.to_string();
+    let mut source = std::error::Error::source(err);
+    while let Some(err) = source {
+        let _ = write!(message, ": {err}");
+        source = err.source();
+    }
+    message
+}
+
+/// Collect all items of the given iterator, or all errors if there are any.
+/// I/O errors stop collecting immediately.
+pub(crate) fn collect_all<T, I>(iter: I) -> Result<Vec<T>>
+where
+    I: IntoIterator<Item = Result<T>>,
+{
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+    for item in iter {
+        match item {
+            Ok(item) => items.push(item),
+            Err(err @ Error::Io(_)) => return Err(err),
+            Err(err) => errors.push(err),
+        }
+    }
+    match errors.len() {
+        0 => Ok(items),
+        1 => Err(errors.remove(0)),
+        _ => Err(Error::Multiple(errors)),
+    }
+}
+
+/// Result with puzzle solving error
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Adding context to errors
+pub trait Context<T> {
+    /// Wrap error with the given context
+    ///
+    /// # Errors
+    /// Error with context
+    fn context<C: fmt::Display>(self, context: C) -> Result<T>;
+
+    /// Wrap error with lazily evaluated context
+    ///
+    /// # Errors
+    /// Error with context
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, f: F) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> Context<T> for Result<T, E> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T> {
+        self.map_err(|err| err.into().context(context))
+    }
+
+    fn with_context<C: fmt::Display, F: FnOnce() -> C>(self, f: F) -> Result<T> {
+        self.map_err(|err| err.into().context(f()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn parse_location() {
+        let err = Error::parse("invalid hand `D`");
+        assert_eq!(err.to_string(), "Parse error: invalid hand `D`");
+        let err = err.at_line(3);
+        assert_eq!(err.to_string(), "Parse error at line 3: invalid hand `D`");
+        let err = err.at_line(5);
+        assert_eq!(err.to_string(), "Parse error at line 3: invalid hand `D`");
+    }
+
+    #[test]
+    fn parse_int() {
+        let err: Error = "x".parse::<u32>().unwrap_err().into();
+        assert!(matches!(err, Error::Parse { line: None, .. }));
+    }
+
+    #[test]
+    fn collect_all_errors() {
+        let items = [Ok(1), Err(Error::parse("x").at_line(2)), Ok(3)];
+        let err = collect_all(items).unwrap_err();
+        assert_eq!(err.to_string(), "Parse error at line 2: x");
+        let items = [
+            Err(Error::parse("x").at_line(1)),
+            Ok(2),
+            Err(Error::parse("y")),
+        ];
+        let err = collect_all(items).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2 errors:\n  Parse error at line 1: x\n  Parse error: y"
+        );
+        assert_eq!(collect_all([Ok(1), Ok(2)]).unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn context() {
+        let res: Result<()> = Err(Error::unsolvable("no marker"));
+        let err = res.context("2022 day 6").unwrap_err();
+        assert_eq!(err.to_string(), "2022 day 6");
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "Unsolvable puzzle: no marker"
+        );
+    }
+}
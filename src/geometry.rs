@@ -0,0 +1,625 @@
+//! Advent of Code: 2D and 3D geometry
+//!
+//! Points use screen coordinates, i.e. y grows downwards (and z grows
+//! towards the viewer).
+
+use crate::{Error, Result};
+use std::fmt;
+use std::hash::Hash;
+use std::iter;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Integer type usable as coordinate
+pub trait Coordinate:
+    Copy
+    + Ord
+    + Default
+    + Hash
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+    /// One (zero is the default)
+    const ONE: Self;
+
+    /// Checked addition, `None` on overflow
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Checked subtraction, `None` on overflow
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_coordinate!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Signed integer type usable as coordinate
+pub trait Signed: Coordinate + Neg<Output = Self> {}
+
+impl<T: Coordinate + Neg<Output = T>> Signed for T {}
+
+/// Absolute difference of the given coordinates
+fn abs_diff<T: Coordinate>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// Sign of the given coordinate (-1, 0 or 1)
+fn signum<T: Signed>(a: T) -> T {
+    match a.cmp(&T::default()) {
+        std::cmp::Ordering::Less => -T::ONE,
+        std::cmp::Ordering::Equal => T::default(),
+        std::cmp::Ordering::Greater => T::ONE,
+    }
+}
+
+/// Direction on a 2D grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    /// Parse direction from `U`/`R`/`D`/`L` or `^`/`>`/`v`/`<`
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "U" | "^" => Ok(Self::Up),
+            "R" | ">" => Ok(Self::Right),
+            "D" | "v" => Ok(Self::Down),
+            "L" | "<" => Ok(Self::Left),
+            _ => Err(Error::parse(format!("invalid direction `{s}`"))),
+        }
+    }
+}
+
+impl Direction {
+    /// All directions, clockwise starting up
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Direction after turning right
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Direction after turning left
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Opposite direction
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Offset of a step in this direction
+    #[must_use]
+    pub fn offset<T: Signed>(self) -> Point2<T> {
+        Direction8::from(self).offset()
+    }
+}
+
+/// Direction on a 2D grid, including diagonals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self::Up,
+            Direction::Right => Self::Right,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+        }
+    }
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting up
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Offset of a step in this direction
+    #[must_use]
+    pub fn offset<T: Signed>(self) -> Point2<T> {
+        let (zero, one) = (T::default(), T::ONE);
+        let (x, y) = match self {
+            Self::Up => (zero, -one),
+            Self::UpRight => (one, -one),
+            Self::Right => (one, zero),
+            Self::DownRight => (one, one),
+            Self::Down => (zero, one),
+            Self::DownLeft => (-one, one),
+            Self::Left => (-one, zero),
+            Self::UpLeft => (-one, -one),
+        };
+        Point2::new(x, y)
+    }
+
+    /// Horizontal and vertical components of this direction
+    fn components(self) -> (Option<Direction>, Option<Direction>) {
+        match self {
+            Self::Up => (None, Some(Direction::Up)),
+            Self::UpRight => (Some(Direction::Right), Some(Direction::Up)),
+            Self::Right => (Some(Direction::Right), None),
+            Self::DownRight => (Some(Direction::Right), Some(Direction::Down)),
+            Self::Down => (None, Some(Direction::Down)),
+            Self::DownLeft => (Some(Direction::Left), Some(Direction::Down)),
+            Self::Left => (Some(Direction::Left), None),
+            Self::UpLeft => (Some(Direction::Left), Some(Direction::Up)),
+        }
+    }
+}
+
+/// Point (or vector) in 2D space
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point2<T> {
+    /// Create point with the given coordinates
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Manhattan distance to the given point
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Chebyshev distance (number of king moves) to the given point
+    #[must_use]
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// Componentwise minimum of this and the given point
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Componentwise maximum of this and the given point
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    /// Neighbor in the given direction, if it's representable (e.g. not
+    /// below zero for unsigned coordinates)
+    #[must_use]
+    pub fn neighbor<D: Into<Direction8>>(self, direction: D) -> Option<Self> {
+        let step = |a: T, direction| match direction {
+            Some(Direction::Left | Direction::Up) => a.checked_sub(T::ONE),
+            Some(Direction::Right | Direction::Down) => a.checked_add(T::ONE),
+            None => Some(a),
+        };
+        let (horizontal, vertical) = direction.into().components();
+        Some(Self::new(
+            step(self.x, horizontal)?,
+            step(self.y, vertical)?,
+        ))
+    }
+
+    /// Neighbors in all 4 directions
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.neighbor(direction))
+    }
+
+    /// Neighbors in all 8 directions, including diagonals
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.neighbor(direction))
+    }
+
+    /// Points in the given direction, starting with the neighbor in that
+    /// direction. Ends if coordinates aren't representable anymore, use
+    /// with [`BoundingBox::contains`] to stay within bounds.
+    pub fn ray<D: Into<Direction8>>(self, direction: D) -> impl Iterator<Item = Self> {
+        let direction = direction.into();
+        iter::successors(self.neighbor(direction), move |p| p.neighbor(direction))
+    }
+}
+
+impl<T: Signed> Point2<T> {
+    /// Componentwise sign (-1, 0 or 1), i.e. a single (possibly diagonal)
+    /// step in the direction of this vector
+    #[must_use]
+    pub fn signum(self) -> Self {
+        Self::new(signum(self.x), signum(self.y))
+    }
+
+    /// Step towards the given point, moving at most 1 in each dimension
+    #[must_use]
+    pub fn step_towards(self, target: Self) -> Self {
+        self + (target - self).signum()
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Point (or vector) in 3D space
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    /// Create point with the given coordinates
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Manhattan distance to the given point
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    /// Chebyshev distance to the given point
+    #[must_use]
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x)
+            .max(abs_diff(self.y, other.y))
+            .max(abs_diff(self.z, other.z))
+    }
+
+    /// Componentwise minimum of this and the given point
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// Componentwise maximum of this and the given point
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    /// Neighbors sharing a face (6 neighbors)
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        let one = T::ONE;
+        [
+            self.x
+                .checked_sub(one)
+                .map(|x| Self::new(x, self.y, self.z)),
+            self.x
+                .checked_add(one)
+                .map(|x| Self::new(x, self.y, self.z)),
+            self.y
+                .checked_sub(one)
+                .map(|y| Self::new(self.x, y, self.z)),
+            self.y
+                .checked_add(one)
+                .map(|y| Self::new(self.x, y, self.z)),
+            self.z
+                .checked_sub(one)
+                .map(|z| Self::new(self.x, self.y, z)),
+            self.z
+                .checked_add(one)
+                .map(|z| Self::new(self.x, self.y, z)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl<T: Signed> Point3<T> {
+    /// Componentwise sign (-1, 0 or 1)
+    #[must_use]
+    pub fn signum(self) -> Self {
+        Self::new(signum(self.x), signum(self.y), signum(self.z))
+    }
+
+    /// Step towards the given point, moving at most 1 in each dimension
+    #[must_use]
+    pub fn step_towards(self, target: Self) -> Self {
+        self + (target - self).signum()
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! impl_vector_ops {
+    ($point:ident { $($c:ident),* }) => {
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: self.$c + rhs.$c),* }
+            }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: self.$c - rhs.$c),* }
+            }
+        }
+
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self { $($c: self.$c * rhs),* }
+            }
+        }
+
+        impl<T: Signed> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),* }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Point2 { x, y });
+impl_vector_ops!(Point3 { x, y, z });
+
+/// Point with componentwise minimum and maximum
+pub trait Bounded: Copy + PartialOrd {
+    /// Componentwise minimum
+    #[must_use]
+    fn min(self, other: Self) -> Self;
+
+    /// Componentwise maximum
+    #[must_use]
+    fn max(self, other: Self) -> Self;
+}
+
+impl<T: Coordinate> Bounded for Point2<T> {
+    fn min(self, other: Self) -> Self {
+        Point2::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Point2::max(self, other)
+    }
+}
+
+impl<T: Coordinate> Bounded for Point3<T> {
+    fn min(self, other: Self) -> Self {
+        Point3::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Point3::max(self, other)
+    }
+}
+
+/// Smallest axis-aligned box containing a set of points (inclusive)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<P> {
+    /// Corner with the smallest coordinates
+    pub min: P,
+    /// Corner with the largest coordinates
+    pub max: P,
+}
+
+impl<P: Bounded> BoundingBox<P> {
+    /// Bounding box of a single point
+    pub fn new(point: P) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Bounding box of the given points, `None` if there are no points
+    pub fn from_points<I: IntoIterator<Item = P>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        bounds.extend(points);
+        Some(bounds)
+    }
+
+    /// Extend this bounding box to contain the given point
+    pub fn insert(&mut self, point: P) {
+        self.min = self.min.min(point);
+        self.max = self.max.max(point);
+    }
+
+    /// Whether the given point is within this bounding box
+    pub fn contains(&self, point: P) -> bool {
+        self.min.min(point) == self.min && self.max.max(point) == self.max
+    }
+}
+
+impl<P: Bounded> Extend<P> for BoundingBox<P> {
+    fn extend<I: IntoIterator<Item = P>>(&mut self, points: I) {
+        for point in points {
+            self.insert(point);
+        }
+    }
+}
+
+impl<T: Coordinate> BoundingBox<Point2<T>> {
+    /// Number of columns
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    /// Number of rows
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    /// All points within this bounding box, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point2<T>> {
+        let (min, max) = (self.min, self.max);
+        iter::successors(Some(min.y), move |y| (*y < max.y).then(|| *y + T::ONE)).flat_map(
+            move |y| {
+                iter::successors(Some(min.x), move |x| (*x < max.x).then(|| *x + T::ONE))
+                    .map(move |x| Point2::new(x, y))
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!("v".parse::<Direction>().unwrap(), Direction::Down);
+        assert_eq!("L".parse::<Direction>().unwrap(), Direction::Left);
+        assert!("X".parse::<Direction>().is_err());
+        assert_eq!(Direction::Up.offset::<i32>(), Point2::new(0, -1));
+        assert_eq!(Direction8::DownLeft.offset::<i32>(), Point2::new(-1, 1));
+    }
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(a - b, Point2::new(-3, 4));
+        assert_eq!(-a * 2, Point2::new(-2, -4));
+        let mut c = Point3::new(1, 2, 3);
+        c += Point3::new(1, 1, 1);
+        assert_eq!(c, Point3::new(2, 3, 4));
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point2::new(1, 2), Point2::new(4, -2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        let (a, b) = (Point3::new(1_u32, 2, 3), Point3::new(3, 0, 8));
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 5);
+    }
+
+    #[test]
+    fn signum_stepping() {
+        let head = Point2::new(2, -3);
+        let tail = Point2::new(0, 0);
+        assert_eq!((head - tail).signum(), Point2::new(1, -1));
+        assert_eq!(tail.step_towards(head), Point2::new(1, -1));
+        assert_eq!(head.step_towards(head), head);
+    }
+
+    #[test]
+    fn unsigned_neighbors() {
+        let p = Point2::new(0_usize, 1);
+        assert_eq!(p.neighbor(Direction::Left), None);
+        assert_eq!(p.neighbor(Direction::Up), Some(Point2::new(0, 0)));
+        assert_eq!(p.neighbors().count(), 3);
+        assert_eq!(p.neighbors8().count(), 5);
+        assert_eq!(Point3::new(0_u8, 0, 0).neighbors().count(), 3);
+        let ray: Vec<_> = Point2::new(2_usize, 0).ray(Direction::Left).collect();
+        assert_eq!(ray, [Point2::new(1, 0), Point2::new(0, 0)]);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = [Point2::new(1, 5), Point2::new(-2, 3), Point2::new(0, 7)];
+        let bounds = BoundingBox::from_points(points).unwrap();
+        assert_eq!(bounds.min, Point2::new(-2, 3));
+        assert_eq!(bounds.max, Point2::new(1, 7));
+        assert_eq!((bounds.width(), bounds.height()), (4, 5));
+        assert!(bounds.contains(Point2::new(0, 3)));
+        assert!(!bounds.contains(Point2::new(2, 3)));
+        assert_eq!(bounds.points().count(), 20);
+        assert_eq!(bounds.points().next(), Some(Point2::new(-2, 3)));
+        assert!(BoundingBox::<Point2<i32>>::from_points([]).is_none());
+    }
+}
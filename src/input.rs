@@ -1,25 +1,47 @@
 //! Advent of Code: puzzle input reading
+//!
+//! Input files aren't available on WebAssembly, inputs can only be read from
+//! strings there.
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::checksum::{self, ChecksumMismatch};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::crypt::{self, Key};
+use crate::error::{collect_all, Error, Result};
 use itertools::Itertools;
-use std::error;
-use std::fs::File;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
 use std::io::{self, BufRead, BufReader, Read};
-use std::path::PathBuf;
+use std::iter;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 /// Path to puzzle input files
-const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input");
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input");
+
+/// Path to example input files
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const EXAMPLE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+
+/// Default puzzle year, whose inputs may also be stored directly in the input path
+pub const YEAR: u16 = 2022;
 
 /// Puzzle input
 #[derive(Debug)]
 pub struct Input<R: Read> {
     reader: BufReader<R>,
+    #[cfg(not(target_arch = "wasm32"))]
+    checksum_mismatch: Option<ChecksumMismatch>,
 }
 
 impl<R: Read> From<R> for Input<R> {
     fn from(reader: R) -> Self {
         Self {
             reader: BufReader::new(reader),
+            #[cfg(not(target_arch = "wasm32"))]
+            checksum_mismatch: None,
         }
     }
 }
@@ -31,30 +53,162 @@ impl<'a> From<&'a str> for Input<&'a [u8]> {
 }
 
 // Constructors
-impl Input<File> {
-    /// Open puzzle input for the given day
+#[cfg(not(target_arch = "wasm32"))]
+impl Input<Box<dyn Read>> {
+    /// Open puzzle input for the given day of the default year
     ///
     /// # Errors
-    /// I/O error
+    /// I/O error, decryption failure or checksum mismatch
     pub fn day(day: usize) -> io::Result<Self> {
-        Self::open(&format!("day{day:02}"))
+        Self::year_day(YEAR, day)
+    }
+
+    /// Open puzzle input for the given day of the given year
+    ///
+    /// Inputs are looked up in a subdirectory per year. Inputs of the default
+    /// year fall back to the flat layout without year subdirectory if there's
+    /// neither a plain text nor an encrypted input in the year subdirectory.
+    ///
+    /// # Errors
+    /// I/O error, decryption failure or checksum mismatch
+    pub fn year_day(year: u16, day: usize) -> io::Result<Self> {
+        Self::year_day_in(Path::new(INPUT_PATH), year, day)
+    }
+
+    /// Open puzzle input for the given day of the given year in the given
+    /// directory (see [`Self::year_day`])
+    fn year_day_in(dir: &Path, year: u16, day: usize) -> io::Result<Self> {
+        let name = format!("day{day:02}");
+        match Self::open_in(dir, &format!("{year}/{name}")) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound && year == YEAR => {
+                Self::open_in(dir, &name)
+            }
+            res => res,
+        }
     }
 
     /// Open puzzle input with the given name
     ///
+    /// If there's no plain text input, an encrypted input is decrypted using
+    /// the input key (see [`Key::load`]). The input is verified against the
+    /// checksum manifest (see [`checksum::verify`]), a mismatch that's only
+    /// a warning is available from [`Input::checksum_mismatch`].
+    ///
     /// # Errors
-    /// I/O error
+    /// I/O error, decryption failure or checksum mismatch
     pub fn open(name: &str) -> io::Result<Self> {
-        let mut filename: PathBuf = INPUT_PATH.into();
-        filename.push(name);
+        Self::open_in(Path::new(INPUT_PATH), name)
+    }
+
+    /// Open puzzle input with the given name in the given directory (see
+    /// [`Self::open`])
+    fn open_in(dir: &Path, name: &str) -> io::Result<Self> {
+        let mut filename = dir.join(name);
         filename.set_extension("txt");
-        let file = File::open(filename)?;
-        Ok(file.into())
+        let data = read_file(&filename)?;
+        let checksum_mismatch = checksum::verify(&filename, &data)?;
+        Ok(Self {
+            checksum_mismatch,
+            ..Self::from(Box::new(io::Cursor::new(data)) as Box<dyn Read>)
+        })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<R: Read> Input<R> {
+    /// Checksum mismatch of this input, if it was opened although its
+    /// checksum doesn't match (see [`checksum::verify`])
+    #[must_use]
+    pub fn checksum_mismatch(&self) -> Option<&ChecksumMismatch> {
+        self.checksum_mismatch.as_ref()
+    }
+}
+
+/// Read contents of the given plain text input file. If there's no plain
+/// text file, an encrypted file is decrypted instead. Fails with
+/// [`io::ErrorKind::NotFound`] only if neither of them exists.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn read_file(filename: &Path) -> io::Result<Vec<u8>> {
+    match fs::read(filename) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let data = match fs::read(crypt::encrypted_path(filename)) {
+                Err(e2) if e2.kind() == io::ErrorKind::NotFound => return Err(e),
+                res => res?,
+            };
+            Key::load()?.decrypt(&data)
+        }
+        res => res,
     }
 }
 
+/// Paths of (plain text and encrypted) files the puzzle input for the given
+/// day may be read from
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn input_files(year: u16, day: usize) -> Vec<PathBuf> {
+    let dir = Path::new(INPUT_PATH);
+    let mut files = vec![dir.join(format!("{year}/day{day:02}.txt"))];
+    if year == YEAR {
+        files.push(dir.join(format!("day{day:02}.txt")));
+    }
+    let encrypted = files.iter().map(|f| crypt::encrypted_path(f)).collect_vec();
+    files.extend(encrypted);
+    files
+}
+
+/// Find example input files for the given day, i.e. `dayNN.txt` and any
+/// variants like `dayNN-2.txt`
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn example_files(year: u16, day: usize) -> io::Result<Vec<PathBuf>> {
+    let dir = Path::new(EXAMPLE_PATH).join(year.to_string());
+    let prefix = format!("day{day:02}");
+    let files = match find_files(&dir, "txt") {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        res => res?,
+    };
+    Ok(files
+        .into_iter()
+        .filter(|file| {
+            file.file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.strip_prefix(&prefix))
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+        })
+        .collect())
+}
+
+/// Whether the given line is blank, i.e. empty or whitespace only
+fn is_blank_line(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Recursively find files with the given extension
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn find_files(dir: &Path, extension: &str) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(find_files(&path, extension)?);
+        } else if path.extension().is_some_and(|ext| ext == extension) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
 // Consuming all input
 impl<R: Read> Input<R> {
+    /// Read all of this input into a string
+    ///
+    /// # Errors
+    /// I/O error
+    pub fn into_string(mut self) -> io::Result<String> {
+        let mut s = String::new();
+        self.reader.read_to_string(&mut s)?;
+        Ok(s)
+    }
+
     /// Iterator over lines of this input
     pub fn lines(self) -> impl Iterator<Item = io::Result<String>> {
         self.reader.lines()
@@ -68,37 +222,92 @@ impl<R: Read> Input<R> {
         self.lines().map(|line| line.map(Into::into))
     }
 
-    /// Iterator over parsed lines of this input
-    pub fn lines_parse<T>(self) -> impl Iterator<Item = io::Result<T>>
+    /// Iterator over parsed lines of this input. Parse errors are located at
+    /// the line they occurred.
+    pub fn lines_parse<T>(self) -> impl Iterator<Item = Result<T>>
     where
         T: FromStr,
-        T::Err: error::Error + Send + Sync + 'static,
+        T::Err: Into<Error>,
     {
-        self.lines().map(|line| {
-            line.and_then(|s| {
-                s.parse()
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-            })
-        })
+        self.lines()
+            .enumerate()
+            .map(|(i, line)| line?.parse().map_err(|e: T::Err| e.into().at_line(i + 1)))
+    }
+
+    /// Parse all lines of this input. Unlike collecting [`Self::lines_parse`],
+    /// parsing continues after invalid lines, so that errors of all invalid
+    /// lines are reported at once.
+    ///
+    /// # Errors
+    /// I/O error or parse errors of all invalid lines
+    pub fn lines_parse_all<T>(self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        collect_all(self.lines_parse())
     }
 
-    /// Iterator over blocks of lines of this input
+    /// Iterator over blocks of lines of this input, separated by blank lines
     pub fn blocks(self) -> impl Iterator<Item = io::Result<Vec<String>>> {
-        fn is_blank_line(line: &io::Result<String>) -> bool {
-            line.as_ref().map(|s| s.trim().is_empty()).unwrap_or(false)
-        }
-        fn is_not_blank_line(line: &io::Result<String>) -> bool {
-            !is_blank_line(line)
-        }
+        self.split_by_delimiter(is_blank_line)
+    }
+
+    /// Iterator over blocks of lines of this input, where every line
+    /// matching the given predicate starts a new block. Lines before the
+    /// first matching line form a block of their own.
+    pub fn split_by<F>(self, is_start: F) -> impl Iterator<Item = io::Result<Vec<String>>>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        self.numbered_split(is_start, false)
+            .map(|block| block.map(|(_, lines)| lines))
+    }
 
-        self.reader.lines().batching(|lines| {
-            let block: io::Result<Vec<_>> = lines
-                .skip_while(is_blank_line)
-                .take_while(is_not_blank_line)
-                .try_collect();
-            match block {
-                Ok(ref lines) if !lines.is_empty() => Some(block),
-                _ => None,
+    /// Iterator over blocks of lines of this input, separated by lines
+    /// matching the given predicate. Separating lines are dropped, empty
+    /// blocks are skipped.
+    pub fn split_by_delimiter<F>(
+        self,
+        is_delimiter: F,
+    ) -> impl Iterator<Item = io::Result<Vec<String>>>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        self.numbered_split(is_delimiter, true)
+            .map(|block| block.map(|(_, lines)| lines))
+    }
+
+    /// Iterator over blocks of lines of this input, split at lines matching
+    /// the given predicate, together with the number of the first line of
+    /// each block (starting at 1). Matching lines either separate blocks
+    /// and are dropped (`delimiter`) or start a new block.
+    fn numbered_split<F>(
+        self,
+        mut is_split: F,
+        delimiter: bool,
+    ) -> impl Iterator<Item = io::Result<(usize, Vec<String>)>>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        let mut lines = self.reader.lines().enumerate();
+        let mut block: Option<(usize, Vec<String>)> = None;
+        iter::from_fn(move || loop {
+            let (i, line) = match lines.next() {
+                None => return block.take().map(Ok),
+                Some((_, Err(e))) => return Some(Err(e)),
+                Some((i, Ok(line))) => (i, line),
+            };
+            let split = is_split(&line);
+            let finished = if split { block.take() } else { None };
+            if !(split && delimiter) {
+                block
+                    .get_or_insert_with(|| (i + 1, Vec::new()))
+                    .1
+                    .push(line);
+            }
+            if let Some(finished) = finished {
+                return Some(Ok(finished));
             }
         })
     }
@@ -112,21 +321,23 @@ impl<R: Read> Input<R> {
             .map(|block| block.map(|b| b.into_iter().map(Into::into).collect()))
     }
 
-    /// Iterator over blocks of parsed lines of this input
-    pub fn blocks_parse<T>(self) -> impl Iterator<Item = io::Result<Vec<T>>>
+    /// Iterator over blocks of parsed lines of this input. Parse errors are
+    /// located at the line they occurred.
+    pub fn blocks_parse<T>(self) -> impl Iterator<Item = Result<Vec<T>>>
     where
         T: FromStr,
-        T::Err: error::Error + Send + Sync + 'static,
+        T::Err: Into<Error>,
     {
-        self.blocks().map(|block| {
-            block.and_then(|b| {
-                b.into_iter()
-                    .map(|line| {
-                        line.parse()
-                            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-                    })
-                    .try_collect()
-            })
+        self.numbered_split(is_blank_line, true).map(|block| {
+            let (start, lines) = block?;
+            lines
+                .into_iter()
+                .enumerate()
+                .map(|(i, line)| {
+                    line.parse()
+                        .map_err(|e: T::Err| e.into().at_line(start + i))
+                })
+                .try_collect()
         })
     }
 }
@@ -153,10 +364,52 @@ mod tests {
         }
     }
 
+    /// Path to test inputs, which don't need the input key
+    #[cfg(not(target_arch = "wasm32"))]
+    const TEST_INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/input");
+
+    #[cfg(not(target_arch = "wasm32"))]
     #[test]
     fn day_one() {
-        let mut lines = Input::day(1).unwrap().lines();
-        let _line = lines.next().unwrap().unwrap();
+        let dir = Path::new(TEST_INPUT_PATH);
+        let mut lines = Input::year_day_in(dir, YEAR, 1).unwrap().lines();
+        assert_eq!(lines.next().unwrap().unwrap(), "1000");
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn year_day_fallback() {
+        let dir = Path::new(TEST_INPUT_PATH);
+        let mut lines = Input::year_day_in(dir, YEAR, 2).unwrap().lines();
+        assert_eq!(lines.next().unwrap().unwrap(), "A Y");
+        let err = Input::year_day_in(dir, 2015, 2).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        // An encrypted input in the year subdirectory takes precedence, even
+        // if it can't be decrypted
+        let err = Input::year_day_in(dir, YEAR, 3).err().unwrap();
+        assert_ne!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn input_file_candidates() {
+        let files = input_files(YEAR, 7);
+        assert_eq!(files.len(), 4);
+        assert!(files[1].ends_with("input/day07.txt"));
+        assert!(files[3].ends_with("input/day07.txt.enc"));
+        assert_eq!(input_files(2015, 7).len(), 2);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn no_examples() {
+        assert!(example_files(2015, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn into_string() {
+        let s = Input::from(INPUT_NUMBERS).into_string().unwrap();
+        assert_eq!(s, INPUT_NUMBERS);
     }
 
     #[test]
@@ -183,6 +436,31 @@ mod tests {
         assert_eq!(items, [11, 22, 33, 44, 55]);
     }
 
+    #[test]
+    fn lines_parse_location() {
+        let err = Input::from("11\n2x\n33\n")
+            .lines_parse::<u32>()
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(err, Error::Parse { line: Some(2), .. }));
+    }
+
+    #[test]
+    fn lines_parse_all() {
+        let numbers: Vec<u32> = Input::from(INPUT_NUMBERS).lines_parse_all().unwrap();
+        assert_eq!(numbers, [11, 22, 33, 44, 55]);
+        let err = Input::from("11\nxx\n33\nyy\n")
+            .lines_parse_all::<u32>()
+            .unwrap_err();
+        match err {
+            Error::Multiple(errors) => {
+                assert!(matches!(errors[0], Error::Parse { line: Some(2), .. }));
+                assert!(matches!(errors[1], Error::Parse { line: Some(4), .. }));
+            }
+            err => panic!("unexpected error {err}"),
+        }
+    }
+
     #[test]
     fn blocks() {
         let blocks: Vec<_> = Input::from(INPUT_BLOCKS).blocks().try_collect().unwrap();
@@ -198,6 +476,42 @@ mod tests {
         assert_eq!(blocks, [["11", "22"], ["33", "44"], ["55", "66"]]);
     }
 
+    #[test]
+    fn split_by() {
+        let input = "x\n$ cd /\n$ ls\ndir a\n1 b\n$ cd a\n";
+        let blocks: Vec<_> = Input::from(input)
+            .split_by(|line| line.starts_with("$ "))
+            .try_collect()
+            .unwrap();
+        assert_eq!(
+            blocks,
+            [
+                vec!["x"],
+                vec!["$ cd /"],
+                vec!["$ ls", "dir a", "1 b"],
+                vec!["$ cd a"]
+            ]
+        );
+    }
+
+    #[test]
+    fn split_by_delimiter() {
+        let blocks: Vec<_> = Input::from("--\n11\n--\n--\n22\n33\n--\n")
+            .split_by_delimiter(|line| line == "--")
+            .try_collect()
+            .unwrap();
+        assert_eq!(blocks, [vec!["11"], vec!["22", "33"]]);
+    }
+
+    #[test]
+    fn blocks_parse_location() {
+        let err = Input::from("\n11\n22\n\n33\nxx\n")
+            .blocks_parse::<u32>()
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(err, Error::Parse { line: Some(6), .. }));
+    }
+
     #[test]
     fn blocks_parse() {
         let blocks: Vec<Vec<u32>> = Input::from(INPUT_BLOCKS)
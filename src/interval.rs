@@ -0,0 +1,265 @@
+//! Advent of Code: sets of integer intervals
+//!
+//! An [`IntervalSet`] stores a set of integers as disjoint inclusive ranges,
+//! merging overlapping and adjacent ranges on insertion.
+
+use crate::geometry::Coordinate;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// Set of integers stored as disjoint, non-adjacent inclusive ranges
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// End of each interval by its start
+    intervals: BTreeMap<T, T>,
+}
+
+impl<T: Coordinate> IntervalSet<T> {
+    /// Create empty set
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            intervals: BTreeMap::new(),
+        }
+    }
+
+    /// Intervals of this set in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|(&start, &end)| start..=end)
+    }
+
+    /// Whether this set is empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Total number of integers in this set. Unlike `T`, the result can
+    /// represent sets covering the whole type.
+    #[must_use]
+    pub fn covered_len(&self) -> u128
+    where
+        T: Into<i128>,
+    {
+        self.intervals
+            .iter()
+            .map(|(&start, &end)| (end.into() - start.into() + 1).unsigned_abs())
+            .sum()
+    }
+
+    /// Intervals overlapping the given range (start and end inclusive)
+    fn overlapping(&self, start: T, end: T) -> Vec<(T, T)> {
+        let mut overlapping: Vec<_> = self
+            .intervals
+            .range(..=end)
+            .rev()
+            .take_while(|(_, &e)| e >= start)
+            .map(|(&s, &e)| (s, e))
+            .collect();
+        overlapping.reverse();
+        overlapping
+    }
+
+    /// Add all integers of the given range
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Merge with overlapping and adjacent intervals
+        let before = start.checked_sub(T::ONE).unwrap_or(start);
+        let after = end.checked_add(T::ONE).unwrap_or(end);
+        for (s, e) in self.overlapping(before, after) {
+            self.intervals.remove(&s);
+            start = start.min(s);
+            end = end.max(e);
+        }
+        self.intervals.insert(start, end);
+    }
+
+    /// Remove all integers of the given range
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        for (s, e) in self.overlapping(start, end) {
+            self.intervals.remove(&s);
+            if s < start {
+                self.intervals.insert(s, start - T::ONE);
+            }
+            if e > end {
+                self.intervals.insert(end + T::ONE, e);
+            }
+        }
+    }
+
+    /// Integers in this or the other set
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    /// Integers in both this and the other set
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        other
+            .iter()
+            .flat_map(|range| {
+                let (start, end) = range.into_inner();
+                self.overlapping(start, end)
+                    .into_iter()
+                    .map(move |(s, e)| s.max(start)..=e.min(end))
+            })
+            .collect()
+    }
+
+    /// Integers in this but not in the other set
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    /// Ranges between the intervals of this set, in ascending order
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals
+            .iter()
+            .zip(self.intervals.keys().skip(1))
+            .map(|((_, &end), &next)| end + T::ONE..=next - T::ONE)
+    }
+
+    /// Whether the given integer is in this set
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        self.contains_range(&(value..=value))
+    }
+
+    /// Whether all integers of the given range are in this set
+    #[must_use]
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        range.is_empty()
+            || self
+                .intervals
+                .range(..=*range.start())
+                .next_back()
+                .is_some_and(|(_, end)| end >= range.end())
+    }
+
+    /// Whether any integer of the given range is in this set
+    #[must_use]
+    pub fn overlaps(&self, range: &RangeInclusive<T>) -> bool {
+        !range.is_empty() && !self.overlapping(*range.start(), *range.end()).is_empty()
+    }
+
+    /// Whether all integers of this set are in the other set
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.iter().all(|range| other.contains_range(&range))
+    }
+
+    /// Whether all integers of the other set are in this set
+    #[must_use]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+}
+
+impl<T: Coordinate> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Coordinate> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Coordinate> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intervals(set: &IntervalSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn insert() {
+        let mut set: IntervalSet<i32> = [1..=3, 7..=9, 12..=12].into_iter().collect();
+        assert_eq!(intervals(&set), [1..=3, 7..=9, 12..=12]);
+        set.insert(4..=5);
+        assert_eq!(intervals(&set), [1..=5, 7..=9, 12..=12]);
+        set.insert(6..=11);
+        assert_eq!(intervals(&set), [1..=12]);
+        #[allow(clippy::reversed_empty_ranges)]
+        set.insert(20..=19);
+        assert_eq!(set.covered_len(), 12);
+    }
+
+    #[test]
+    fn remove() {
+        let mut set = IntervalSet::from(1..=10);
+        set.remove(3..=4);
+        set.remove(10..=20);
+        assert_eq!(intervals(&set), [1..=2, 5..=9]);
+        set.remove(0..=6);
+        assert_eq!(intervals(&set), [7..=9]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: IntervalSet<i32> = [0..=5, 10..=15].into_iter().collect();
+        let b: IntervalSet<i32> = [3..=12, 20..=20].into_iter().collect();
+        assert_eq!(intervals(&a.union(&b)), [0..=15, 20..=20]);
+        assert_eq!(intervals(&a.intersection(&b)), [3..=5, 10..=12]);
+        assert_eq!(intervals(&a.difference(&b)), [0..=2, 13..=15]);
+        assert_eq!(intervals(&b.difference(&a)), [6..=9, 20..=20]);
+        assert!(a.intersection(&IntervalSet::from(6..=9)).is_empty());
+    }
+
+    #[test]
+    fn gaps() {
+        let set: IntervalSet<u8> = [0..=2, 5..=5, 7..=255].into_iter().collect();
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [3..=4, 6..=6]);
+        assert_eq!(set.covered_len(), 253);
+        assert_eq!(IntervalSet::from(0..=255_u8).gaps().count(), 0);
+        assert_eq!(IntervalSet::from(0..=u8::MAX).covered_len(), 256);
+        assert_eq!(
+            IntervalSet::from(i64::MIN..=i64::MAX).covered_len(),
+            1 << 64
+        );
+    }
+
+    #[test]
+    fn containment() {
+        let set: IntervalSet<u32> = [2..=4, 6..=8].into_iter().collect();
+        assert!(set.contains(2));
+        assert!(!set.contains(5));
+        assert!(set.contains_range(&(6..=8)));
+        assert!(!set.contains_range(&(4..=6)));
+        assert!(set.overlaps(&(4..=6)));
+        assert!(!set.overlaps(&(9..=10)));
+        assert!(IntervalSet::from(3..=4).is_subset(&set));
+        assert!(set.is_superset(&IntervalSet::from(7..=7)));
+        assert!(!set.is_subset(&IntervalSet::from(2..=7)));
+    }
+}
@@ -2,5 +2,57 @@
 
 #![warn(clippy::pedantic)]
 
+mod answer;
+pub use answer::{Answer, Answers};
+
+pub mod bitset;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod capi;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod checksum;
+
+mod error;
+pub use error::{Context, Error, Result};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod crypt;
+pub mod cycle;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod dashboard;
+#[cfg(any(test, not(target_arch = "wasm32")))]
+mod diff;
+pub mod differential;
+pub mod geometry;
+pub mod interval;
+pub mod minimize;
+
 mod input;
-pub use input::Input;
+pub use input::{Input, YEAR};
+
+mod solution;
+pub use solution::{Solution, Solver};
+
+pub mod ocr;
+
+#[cfg(feature = "python")]
+mod python;
+
+pub mod registry;
+pub use registry::Puzzle;
+
+pub mod runner;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod scaffold;
+pub mod search;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod server;
+#[cfg(test)]
+mod snapshot;
+#[cfg(test)]
+mod tempdir;
+pub mod visualize;
+pub mod wasm;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod watch;
+
+pub mod year2022;
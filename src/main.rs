@@ -0,0 +1,323 @@
+//! Advent of Code: puzzle runner
+//!
+//! Usage:
+//! - `advent-of-code-2022 [[YEAR] DAY]`: solve puzzles (all registered
+//!   puzzles if no day is given)
+//! - `advent-of-code-2022 keygen`: create a new input key file
+//! - `advent-of-code-2022 encrypt [--remove]`: encrypt all plain text inputs
+//!   (and remove them once encrypted)
+//! - `advent-of-code-2022 decrypt`: decrypt all encrypted inputs
+//! - `advent-of-code-2022 checksum`: refresh the input checksum manifest
+//! - `advent-of-code-2022 new [YEAR] DAY`: create a new puzzle solution
+//! - `advent-of-code-2022 watch [YEAR] DAY`: re-run a puzzle whenever its
+//!   input, examples or source change
+//! - `advent-of-code-2022 visualize [YEAR] DAY [--svg DIR]`: animate solving
+//!   a puzzle in the terminal (or export SVG frames to the given directory)
+//! - `advent-of-code-2022 minimize [YEAR] DAY INPUT [--panic | --error |
+//!   --reference COMMAND...]`: shrink an input while solving it still panics
+//!   (default), returns an error or disagrees with a reference implementation
+//!   reading the input from stdin, and write it to `INPUT.min.txt`
+//! - `advent-of-code-2022 generate [YEAR] DAY [--seed N] [--size N]`: print
+//!   a random puzzle input of roughly the given size
+//! - `advent-of-code-2022 serve [PORT]`: answer puzzles over HTTP on the
+//!   given local port (default 8022)
+//! - `advent-of-code-2022 dashboard [YEAR]`: interactive terminal dashboard
+//!   of all days
+
+use advent_of_code_2022::dashboard::Dashboard;
+use advent_of_code_2022::minimize::{self, Failure};
+use advent_of_code_2022::server::Server;
+use advent_of_code_2022::visualize::{Render, Svg, Terminal};
+use advent_of_code_2022::watch::{Change, Watcher};
+use advent_of_code_2022::{
+    checksum, crypt, crypt::Key, registry, scaffold, Answers, Context, Puzzle, Result, YEAR,
+};
+use itertools::Itertools;
+use std::{env, error, fs, io, panic, path::Path, process, thread, time::Duration};
+
+/// Read the input of the given puzzle, warning about a checksum mismatch
+fn read_input(puzzle: &Puzzle) -> Result<String> {
+    let input = puzzle.input()?;
+    if let Some(mismatch) = input.checksum_mismatch() {
+        eprintln!("Warning: {mismatch}");
+    }
+    Ok(input.into_string()?)
+}
+
+fn run(puzzle: &Puzzle, answers: &Answers) -> Result<()> {
+    let context = || format!("{} day {}", puzzle.year, puzzle.day);
+    let input = read_input(puzzle).with_context(context)?;
+    let solver = puzzle.parse(&input).with_context(context)?;
+    println!("{}:", context());
+    for part in 1..=2 {
+        let Some(answer) = solver.solve(part) else {
+            continue;
+        };
+        let part_context = || format!("{} part {part}", context());
+        let answer = answer.with_context(part_context)?;
+        if answer.to_string().contains('\n') {
+            println!("Part {part}:\n{answer}");
+        } else {
+            println!("Part {part}: {answer}");
+        }
+        answers
+            .check(puzzle.year, puzzle.day, part, &answer)
+            .with_context(part_context)?;
+    }
+    Ok(())
+}
+
+/// Parse optional year and day from command line arguments
+fn year_and_day(args: &[String]) -> Result<Option<(u16, usize)>, Box<dyn error::Error>> {
+    let args: Vec<usize> = args.iter().map(|arg| arg.parse()).try_collect()?;
+    match args[..] {
+        [] => Ok(None),
+        [day] => Ok(Some((YEAR, day))),
+        [year, day] => Ok(Some((year.try_into()?, day))),
+        _ => Err("Expected [YEAR] DAY arguments".into()),
+    }
+}
+
+fn solve(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let puzzles: Vec<_> = match year_and_day(args)? {
+        None => registry::puzzles().collect(),
+        Some((year, day)) => registry::find(year, day).into_iter().collect(),
+    };
+    if puzzles.is_empty() {
+        return Err("No such puzzle".into());
+    }
+    let answers = Answers::load()?;
+    for puzzle in puzzles {
+        run(puzzle, &answers)?;
+    }
+    Ok(())
+}
+
+fn new_day(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let (year, day) = year_and_day(args)?.ok_or("Missing day")?;
+    if registry::find(year, day).is_some() {
+        return Err(format!("{year} day {day} already exists").into());
+    }
+    for path in scaffold::generate(year, day)? {
+        println!("Created {}", path.display());
+    }
+    Ok(())
+}
+
+/// Rebuild the runner, returns whether the build succeeded
+fn rebuild() -> io::Result<bool> {
+    let mut cargo = process::Command::new(env!("CARGO"));
+    cargo.args(["build", "--quiet"]);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    Ok(cargo.status()?.success())
+}
+
+/// Replace this process by running the (rebuilt) runner with the same
+/// arguments. Only returns on failure.
+#[cfg(unix)]
+fn restart() -> io::Error {
+    use std::os::unix::process::CommandExt;
+    let mut args = env::args_os();
+    let program = args.next().unwrap_or_default();
+    process::Command::new(program).args(args).exec()
+}
+
+/// Run the (rebuilt) runner with the same arguments and exit with its
+/// status. Only returns on failure.
+#[cfg(not(unix))]
+fn restart() -> io::Error {
+    let mut args = env::args_os();
+    let program = args.next().unwrap_or_default();
+    match process::Command::new(program).args(args).status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => e,
+    }
+}
+
+fn watch(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let (year, day) = year_and_day(args)?.ok_or("Missing day")?;
+    let puzzle = registry::find(year, day).ok_or("No such puzzle")?;
+    let mut watcher = Watcher::new(*puzzle);
+    print!("{}", watcher.run());
+    loop {
+        match watcher.wait() {
+            Change::Input => print!("\n{}", watcher.run()),
+            Change::Source => {
+                println!("\nSolution source changed, rebuilding");
+                if rebuild()? {
+                    return Err(restart().into());
+                }
+            }
+        }
+    }
+}
+
+fn visualize(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let (args, svg_dir) = match args.iter().position(|arg| arg == "--svg") {
+        Some(i) => (
+            &args[..i],
+            Some(args.get(i + 1).ok_or("Missing SVG directory")?),
+        ),
+        None => (args, None),
+    };
+    let (year, day) = year_and_day(args)?.ok_or("Missing day")?;
+    let puzzle = registry::find(year, day).ok_or("No such puzzle")?;
+    let context = || format!("{year} day {day}");
+    let input = read_input(puzzle).with_context(context)?;
+    let solver = puzzle.parse(&input).with_context(context)?;
+    let mut render: Box<dyn Render> = match svg_dir {
+        Some(dir) => Box::new(Svg::new(dir)?),
+        None => Box::new(Terminal::new(io::stdout(), Duration::from_millis(20))),
+    };
+    solver
+        .visualize(render.as_mut())
+        .ok_or_else(|| format!("{} has no visualization", context()))?
+        .with_context(context)?;
+    if let Some(dir) = svg_dir {
+        println!("Exported frames to {dir}");
+    }
+    Ok(())
+}
+
+fn minimize(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let flag = args.iter().position(|arg| arg.starts_with("--"));
+    let (args, failure) = match flag.map(|i| (&args[..i], &args[i..])) {
+        None => (args, Failure::Panic),
+        Some((args, [flag])) if flag == "--panic" => (args, Failure::Panic),
+        Some((args, [flag])) if flag == "--error" => (args, Failure::Error),
+        Some((args, [flag, reference @ ..])) if flag == "--reference" && !reference.is_empty() => {
+            (args, Failure::Mismatch(reference.to_vec()))
+        }
+        Some((_, [flag, ..])) => return Err(format!("Invalid option {flag}").into()),
+        Some((_, [])) => unreachable!(),
+    };
+    let (path, args) = args.split_last().ok_or("Missing input file")?;
+    let (year, day) = year_and_day(args)?.ok_or("Missing day")?;
+    let puzzle = registry::find(year, day).ok_or("No such puzzle")?;
+    let input = fs::read_to_string(path)?;
+    // Panics are expected while minimizing, don't report each of them
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let minimal = failure
+        .occurs(puzzle, &input)
+        .then(|| minimize::minimize(&input, |input| failure.occurs(puzzle, input)));
+    panic::set_hook(hook);
+    let minimal = minimal.ok_or_else(|| format!("Failure doesn't occur with {path}"))?;
+    let output = Path::new(path).with_extension("min.txt");
+    fs::write(&output, &minimal)?;
+    println!(
+        "Minimized {} lines to {} lines in {}",
+        input.lines().count(),
+        minimal.lines().count(),
+        output.display()
+    );
+    Ok(())
+}
+
+fn generate(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let flag = args.iter().position(|arg| arg.starts_with("--"));
+    let (args, options) = args.split_at(flag.unwrap_or(args.len()));
+    let (mut seed, mut size) = (0, 1000);
+    for option in options.chunks(2) {
+        match option {
+            [name, value] if name == "--seed" => seed = value.parse()?,
+            [name, value] if name == "--size" => size = value.parse()?,
+            [name, ..] => return Err(format!("Invalid option {name}").into()),
+            [] => unreachable!(),
+        }
+    }
+    let (year, day) = year_and_day(args)?.ok_or("Missing day")?;
+    let puzzle = registry::find(year, day).ok_or("No such puzzle")?;
+    let input = puzzle
+        .generate(seed, size)
+        .ok_or_else(|| format!("{year} day {day} has no input generator"))?;
+    print!("{input}");
+    Ok(())
+}
+
+fn serve(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let port: u16 = match args {
+        [] => 8022,
+        [port] => port.parse()?,
+        _ => return Err("Expected [PORT] argument".into()),
+    };
+    let server = Server::bind(("127.0.0.1", port))?;
+    let workers = thread::available_parallelism().map_or(4, usize::from);
+    println!("Listening on http://{}", server.local_addr()?);
+    server.run(workers);
+    Ok(())
+}
+
+fn dashboard(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let year = match args {
+        [] => YEAR,
+        [year] => year.parse()?,
+        _ => return Err("Expected [YEAR] argument".into()),
+    };
+    Dashboard::new(year, Answers::load()?).run()?;
+    Ok(())
+}
+
+fn keygen() -> Result<(), Box<dyn error::Error>> {
+    let path = Key::path();
+    if path.exists() {
+        return Err(format!("Key file {} already exists", path.display()).into());
+    }
+    fs::write(&path, Key::generate().to_hex() + "\n")?;
+    println!("Created key file {}", path.display());
+    Ok(())
+}
+
+fn encrypt(args: &[String]) -> Result<(), Box<dyn error::Error>> {
+    let remove_plaintext = match args {
+        [] => false,
+        [flag] if flag == "--remove" => true,
+        _ => return Err("Expected [--remove] argument".into()),
+    };
+    for path in crypt::encrypt_inputs(&Key::load()?, remove_plaintext)? {
+        println!("Encrypted {}", path.display());
+    }
+    Ok(())
+}
+
+fn decrypt() -> Result<(), Box<dyn error::Error>> {
+    for path in crypt::decrypt_inputs(&Key::load()?)? {
+        println!("Decrypted {}", path.display());
+    }
+    Ok(())
+}
+
+fn refresh_checksums() -> Result<(), Box<dyn error::Error>> {
+    let manifest = checksum::refresh()?;
+    println!("Recorded checksums of {} inputs", manifest.len());
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let res = match args.first().map(String::as_str) {
+        Some("keygen") => keygen(),
+        Some("encrypt") => encrypt(&args[1..]),
+        Some("decrypt") => decrypt(),
+        Some("checksum") => refresh_checksums(),
+        Some("new") => new_day(&args[1..]),
+        Some("watch") => watch(&args[1..]),
+        Some("visualize") => visualize(&args[1..]),
+        Some("minimize") => minimize(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("serve") => serve(&args[1..]),
+        Some("dashboard") => dashboard(&args[1..]),
+        _ => solve(&args),
+    };
+    if let Err(err) = res {
+        eprintln!("Error: {err}");
+        let mut source = err.source();
+        while let Some(err) = source {
+            eprintln!("  Caused by: {err}");
+            source = err.source();
+        }
+        process::exit(1);
+    }
+}
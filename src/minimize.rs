@@ -0,0 +1,209 @@
+//! Advent of Code: input minimization
+//!
+//! Shrinks a failing puzzle input to a small reproducer by delta debugging:
+//! blocks (separated by blank lines) and then lines are removed as long as
+//! the failure still occurs.
+
+use crate::{Answer, Puzzle, Result};
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::process::{Command, Stdio};
+
+/// Failure to preserve while minimizing an input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// Parsing or solving panics
+    Panic,
+    /// Parsing or solving returns an error
+    Error,
+    /// Answers differ from those of a reference implementation, given as
+    /// command line. The reference reads the input from stdin and prints
+    /// one answer per line.
+    Mismatch(Vec<String>),
+}
+
+impl Failure {
+    /// Whether the failure occurs when solving the given input
+    #[must_use]
+    pub fn occurs(&self, puzzle: &Puzzle, input: &str) -> bool {
+        let answers = panic::catch_unwind(AssertUnwindSafe(|| -> Result<Vec<Answer>> {
+            let solver = puzzle.parse(input)?;
+            (1..).map_while(|part| solver.solve(part)).collect()
+        }));
+        match (self, answers) {
+            (Self::Panic, Err(_)) | (Self::Error, Ok(Err(_))) => true,
+            (Self::Mismatch(reference), Ok(Ok(answers))) => reference_answers(reference, input)
+                .is_ok_and(|expected| answers.iter().zip(&expected).any(|(a, b)| a != b)),
+            _ => false,
+        }
+    }
+}
+
+/// Run the given reference implementation on the given input
+fn reference_answers(command: &[String], input: &str) -> io::Result<Vec<Answer>> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing command"))?;
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The reference may exit without reading all input
+        let _ = stdin.write_all(input.as_bytes());
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::other("reference failed"));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| {
+            let Ok(answer) = line.parse();
+            answer
+        })
+        .collect())
+}
+
+/// Minimize the given items with delta debugging: remove chunks of
+/// decreasing size as long as the given predicate still fails. The result
+/// is 1-minimal, i.e. removing any single item makes the failure disappear.
+pub fn ddmin<T: Clone, F: FnMut(&[T]) -> bool>(mut items: Vec<T>, mut fails: F) -> Vec<T> {
+    let mut chunks = 2;
+    while items.len() >= 2 {
+        let chunk_len = items.len().div_ceil(chunks);
+        let mut reduced = false;
+        for start in (0..items.len()).step_by(chunk_len) {
+            let end = (start + chunk_len).min(items.len());
+            // Try the chunk alone, then everything except the chunk
+            if fails(&items[start..end]) {
+                items = items[start..end].to_vec();
+                chunks = 2;
+                reduced = true;
+                break;
+            }
+            let complement = [&items[..start], &items[end..]].concat();
+            if fails(&complement) {
+                items = complement;
+                chunks = (chunks - 1).max(2);
+                reduced = true;
+                break;
+            }
+        }
+        if !reduced {
+            if chunks >= items.len() {
+                break;
+            }
+            chunks = (chunks * 2).min(items.len());
+        }
+    }
+    items
+}
+
+/// Minimize the given input, first by removing blocks separated by blank
+/// lines and then by removing single lines
+pub fn minimize<F: FnMut(&str) -> bool>(input: &str, mut fails: F) -> String {
+    let blocks: Vec<&str> = input
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+        .collect();
+    let join_blocks = |blocks: &[&str]| blocks.join("\n\n") + "\n";
+    let input = if blocks.len() > 1 {
+        join_blocks(&ddmin(blocks, |blocks| fails(&join_blocks(blocks))))
+    } else {
+        input.to_string()
+    };
+    let join_lines = |lines: &[&str]| lines.join("\n") + "\n";
+    let lines: Vec<&str> = input.lines().collect();
+    join_lines(&ddmin(lines, |lines| fails(&join_lines(lines))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Input, Solution};
+
+    /// Solution panicking on input containing `boom` and failing on input
+    /// containing `bad`. Answers with the number of lines.
+    #[derive(Debug)]
+    struct Fragile {
+        lines: usize,
+        bad: bool,
+    }
+
+    impl Solution for Fragile {
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse<R: io::Read>(input: Input<R>) -> Result<Self> {
+            let input = input.into_string()?;
+            assert!(!input.contains("boom"), "boom");
+            Ok(Self {
+                lines: input.lines().count(),
+                bad: input.contains("bad"),
+            })
+        }
+
+        fn part_1(&self) -> Result<usize> {
+            Ok(self.lines)
+        }
+
+        fn part_2(&self) -> Result<usize> {
+            if self.bad {
+                Err(crate::Error::unsolvable("bad"))
+            } else {
+                Ok(self.lines)
+            }
+        }
+    }
+
+    #[test]
+    fn ddmin_items() {
+        let items: Vec<u32> = (0..100).collect();
+        let minimal = ddmin(items, |items| items.contains(&13) && items.contains(&77));
+        assert_eq!(minimal, [13, 77]);
+        assert_eq!(ddmin(vec![1, 2, 3], |_| false), [1, 2, 3]);
+    }
+
+    #[test]
+    fn minimize_blocks_and_lines() {
+        let input = "a\nb\n\nc\nx\nd\n\ne\ny\n";
+        let minimal = minimize(input, |input| input.contains('x') && input.contains('y'));
+        assert_eq!(minimal, "x\ny\n");
+        let minimal = minimize("1\n2\n3\n", |input| input.contains('2'));
+        assert_eq!(minimal, "2\n");
+    }
+
+    #[test]
+    fn failures() {
+        let puzzle = Puzzle::new::<Fragile>(2022, 99);
+        assert!(Failure::Error.occurs(&puzzle, "bad\n"));
+        assert!(!Failure::Error.occurs(&puzzle, "1\n"));
+        assert!(!Failure::Panic.occurs(&puzzle, "bad\n"));
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let panics = Failure::Panic.occurs(&puzzle, "boom\n");
+        panic::set_hook(hook);
+        assert!(panics);
+        let minimal = minimize("1\n2\nbad\n4\n", |input| {
+            Failure::Error.occurs(&puzzle, input)
+        });
+        assert_eq!(minimal, "bad\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn mismatch() {
+        let puzzle = Puzzle::new::<Fragile>(2022, 99);
+        let reference = |script: &str| {
+            let script = format!("cat >/dev/null; {script}");
+            Failure::Mismatch(["sh", "-c", &script].map(String::from).to_vec())
+        };
+        assert!(reference("echo 3").occurs(&puzzle, "1\n2\n"));
+        assert!(!reference("echo 2").occurs(&puzzle, "1\n2\n"));
+        assert!(!reference("exit 1").occurs(&puzzle, "1\n2\n"));
+    }
+}
@@ -0,0 +1,205 @@
+//! Advent of Code: recognition of block letters drawn in pixels
+//!
+//! Some puzzles draw their answer as letters in a pixel image (`#` for lit
+//! pixels). Images 6 pixels high use the small font with 4 pixel wide
+//! letters, images 10 pixels high use the large font with 6 pixel wide
+//! letters.
+
+use thiserror::Error;
+
+/// Font of block letters
+struct Font {
+    /// Letters of the font
+    letters: &'static str,
+    /// Drawing of all letters next to each other
+    drawing: &'static str,
+}
+
+/// Small font (4x6 letters)
+const SMALL_FONT: Font = Font {
+    letters: "ABCEFGHIJKLOPRSUYZ",
+    drawing: "\
+    .##...###....##...####..####...##...#..#...###....##..#..#..#......##...###...###....###..#..#..#...#..####\n\
+    #..#..#..#..#..#..#.....#.....#..#..#..#....#......#..#.#...#.....#..#..#..#..#..#..#.....#..#..#...#.....#\n\
+    #..#..###...#.....###...###...#.....####....#......#..##....#.....#..#..#..#..#..#..#.....#..#...#.#.....#.\n\
+    ####..#..#..#.....#.....#.....#.##..#..#....#......#..#.#...#.....#..#..###...###....##...#..#....#.....#..\n\
+    #..#..#..#..#..#..#.....#.....#..#..#..#....#...#..#..#.#...#.....#..#..#.....#.#......#..#..#....#....#...\n\
+    #..#..###....##...####..#......###..#..#...###...##...#..#..####...##...#.....#..#..###....##.....#....####",
+};
+
+/// Large font (6x10 letters)
+const LARGE_FONT: Font = Font {
+    letters: "ABCEFGHJKLNPRXZ",
+    drawing: "\
+    ..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######\n\
+    .#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#\n\
+    #....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#\n\
+    #....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#.\n\
+    #....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#..\n\
+    ######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#...\n\
+    #....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#....\n\
+    #....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.....\n\
+    #....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.....\n\
+    #....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######",
+};
+
+impl Font {
+    /// Find the letter matching the given glyph
+    fn letter(&self, glyph: &Pixels) -> Option<char> {
+        glyphs(&parse(self.drawing))
+            .into_iter()
+            .zip(self.letters.chars())
+            .find(|((_, pixels), _)| pixels == glyph)
+            .map(|(_, letter)| letter)
+    }
+}
+
+/// Letter recognition error
+#[derive(Debug, Error)]
+pub enum OcrError {
+    /// There's no font with letters of the image's height
+    #[error("Unsupported letter height {0} (expected 6 or 10)")]
+    UnsupportedHeight(usize),
+    /// Glyph doesn't match any letter of the font
+    #[error("Unknown glyph at column {column}:\n{glyph}")]
+    UnknownGlyph { column: usize, glyph: String },
+}
+
+/// Rows of pixels
+type Pixels = Vec<Vec<bool>>;
+
+/// Parse pixels from lines of `#` (lit) and any other character (unlit)
+fn parse(image: &str) -> Pixels {
+    image
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect()
+}
+
+/// Draw pixels as lines of `#` (lit) and `.` (unlit)
+fn draw(pixels: &[Vec<bool>]) -> String {
+    pixels
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&lit| if lit { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Split pixels into glyphs at columns without lit pixels. Returns the
+/// starting column and pixels of each glyph.
+fn glyphs<R: AsRef<[bool]>>(rows: &[R]) -> Vec<(usize, Pixels)> {
+    let width = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    let lit = |x: usize, y: usize| rows[y].as_ref().get(x).copied().unwrap_or(false);
+    let mut glyphs = Vec::new();
+    let mut start = None;
+    for x in 0..=width {
+        let blank = (0..rows.len()).all(|y| !lit(x, y));
+        match start {
+            None if !blank => start = Some(x),
+            Some(from) if blank => {
+                let glyph = (0..rows.len())
+                    .map(|y| (from..x).map(|x| lit(x, y)).collect())
+                    .collect();
+                glyphs.push((from, glyph));
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    glyphs
+}
+
+/// Recognize letters in the given rows of pixels (`true` for lit pixels).
+/// Rows without lit pixels above and below the letters are ignored.
+///
+/// # Errors
+/// Unsupported letter height or unknown glyph
+pub fn recognize_pixels<R: AsRef<[bool]>>(rows: &[R]) -> Result<String, OcrError> {
+    let is_lit = |row: &R| row.as_ref().contains(&true);
+    let top = rows.iter().position(is_lit).unwrap_or(0);
+    let bottom = rows.iter().rposition(is_lit).map_or(0, |y| y + 1);
+    let rows = &rows[top..bottom.max(top)];
+    let font = match rows.len() {
+        0 => return Ok(String::new()),
+        6 => SMALL_FONT,
+        10 => LARGE_FONT,
+        height => return Err(OcrError::UnsupportedHeight(height)),
+    };
+    glyphs(rows)
+        .into_iter()
+        .map(|(column, glyph)| {
+            font.letter(&glyph).ok_or_else(|| OcrError::UnknownGlyph {
+                column,
+                glyph: draw(&glyph),
+            })
+        })
+        .collect()
+}
+
+/// Recognize letters in the given image of lines of `#` (lit pixels) and
+/// spaces or `.` (unlit pixels)
+///
+/// # Errors
+/// Unsupported letter height or unknown glyph
+pub fn recognize(image: &str) -> Result<String, OcrError> {
+    recognize_pixels(&parse(image))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_font() {
+        let image = "\
+            #### ####  ##  #### ###  #  # ###  #### \n\
+            #    #    #  # #    #  # #  # #  # #    \n\
+            ###  ###  #    ###  #  # #  # #  # ###  \n\
+            #    #    # ## #    ###  #  # ###  #    \n\
+            #    #    #  # #    # #  #  # # #  #    \n\
+            #### #     ### #### #  #  ##  #  # #### \n";
+        assert_eq!(recognize(image).unwrap(), "EFGERURE");
+    }
+
+    #[test]
+    fn all_letters() {
+        for font in [SMALL_FONT, LARGE_FONT] {
+            assert_eq!(recognize(font.drawing).unwrap(), font.letters);
+        }
+    }
+
+    #[test]
+    fn pixels() {
+        let image = [
+            [false, true, true, false, false],
+            [true, false, false, true, false],
+        ];
+        let mut image = image.map(Vec::from).to_vec();
+        image.extend(parse("#..#\n####\n#..#\n#..#"));
+        assert_eq!(recognize_pixels(&image).unwrap(), "A");
+    }
+
+    #[test]
+    fn surrounding_blank_rows() {
+        let image = "\n....\n.##.\n#..#\n#..#\n####\n#..#\n#..#\n\n";
+        assert_eq!(recognize(image).unwrap(), "A");
+        assert_eq!(recognize("").unwrap(), "");
+    }
+
+    #[test]
+    fn unknown_glyph() {
+        let err = recognize("#..#\n#..#\n####\n#..#\n#..#\n#.##").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown glyph at column 0:\n#..#\n#..#\n####\n#..#\n#..#\n#.##"
+        );
+        assert!(matches!(
+            recognize("#\n#\n#"),
+            Err(OcrError::UnsupportedHeight(3))
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+//! Advent of Code: Python bindings
+//!
+//! Extension module `advent_of_code_2022` (feature `python`), built with
+//! maturin. Each day is a submodule with a `solve(input, part)` function,
+//! some also expose their domain types. Integer answers are returned as
+//! `int`, all others as `str`. Parse errors raise `ValueError`, failures to
+//! answer a part raise `RuntimeError`.
+//!
+//! Build and install into the active virtualenv with `maturin develop`.
+
+use crate::error::describe;
+use crate::registry::{self, SolveError};
+use crate::year2022;
+use crate::{Answer, Error, Input, Solution};
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+
+impl From<Error> for PyErr {
+    fn from(err: Error) -> Self {
+        let mut cause = &err;
+        while let Error::Context { source, .. } = cause {
+            cause = source;
+        }
+        match cause {
+            Error::Parse { .. } | Error::Multiple(_) => PyValueError::new_err(describe(&err)),
+            _ => PyRuntimeError::new_err(describe(&err)),
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for Answer {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        match self {
+            Self::Integer(n) => Ok(n.into_pyobject(py)?.into_any()),
+            Self::Text(s) => Ok(s.into_pyobject(py)?.into_any()),
+            Self::Image(_) => Ok(self.to_string().into_pyobject(py)?.into_any()),
+        }
+    }
+}
+
+/// Days of the given year with puzzle solutions
+#[pyfunction]
+fn days(year: u16) -> Vec<usize> {
+    registry::year(year).map(|puzzle| puzzle.day).collect()
+}
+
+/// Answer the given part of a puzzle for the given input
+#[pyfunction]
+fn solve(year: u16, day: usize, part: u8, input: &str) -> PyResult<Answer> {
+    registry::solve(year, day, part, input).map_err(|e| match e {
+        SolveError::Parse(e) | SolveError::Solve(e) => e.into(),
+        e => PyValueError::new_err(e.to_string()),
+    })
+}
+
+/// Filesystem reconstructed from terminal output
+#[pyclass(name = "Filesystem", module = "advent_of_code_2022.day07", frozen)]
+struct Filesystem(year2022::day07::Filesystem);
+
+#[pymethods]
+impl Filesystem {
+    #[new]
+    fn new(terminal_output: &str) -> PyResult<Self> {
+        Ok(Self(year2022::day07::Filesystem::parse(Input::from(
+            terminal_output,
+        ))?))
+    }
+
+    /// Total size of all files
+    fn used_size(&self) -> usize {
+        self.0.used_size()
+    }
+
+    /// Sizes of all directories, including their subdirectories
+    fn dir_sizes(&self) -> Vec<usize> {
+        self.0.dir_sizes().collect()
+    }
+
+    /// Sum of the sizes of all directories up to the given size
+    fn sum_of_dir_sizes(&self, max_dir_size: usize) -> usize {
+        self.0.sum_of_dir_sizes(max_dir_size)
+    }
+
+    /// Size of the smallest directory to delete to get the desired free size
+    fn size_of_dir_to_delete(
+        &self,
+        total_size: usize,
+        desired_free_size: usize,
+    ) -> PyResult<usize> {
+        Ok(self
+            .0
+            .size_of_dir_to_delete(total_size, desired_free_size)?)
+    }
+}
+
+/// CPU running a program, keeping its registers between calls
+#[pyclass(name = "Cpu", module = "advent_of_code_2022.day10")]
+struct Cpu {
+    program: year2022::day10::Program,
+    pc: usize,
+    cycles: usize,
+    x: i32,
+}
+
+impl Cpu {
+    /// Run the given function on the CPU and keep the resulting registers
+    fn with_cpu<'a, T>(&'a mut self, f: impl FnOnce(&mut year2022::day10::Cpu<'a>) -> T) -> T {
+        let mut cpu = year2022::day10::Cpu {
+            instructions: &self.program.0,
+            pc: self.pc,
+            cycles: self.cycles,
+            x: self.x,
+        };
+        let res = f(&mut cpu);
+        (self.pc, self.cycles, self.x) = (cpu.pc, cpu.cycles, cpu.x);
+        res
+    }
+}
+
+#[pymethods]
+impl Cpu {
+    #[new]
+    fn new(program: &str) -> PyResult<Self> {
+        let program = year2022::day10::Program::parse(Input::from(program))?;
+        let cpu = year2022::day10::Cpu::new(&program.0);
+        let (pc, cycles, x) = (cpu.pc, cpu.cycles, cpu.x);
+        Ok(Self {
+            program,
+            pc,
+            cycles,
+            x,
+        })
+    }
+
+    /// Value of the X register
+    #[getter]
+    fn x(&self) -> i32 {
+        self.x
+    }
+
+    /// Number of cycles run so far
+    #[getter]
+    fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    /// Run the next instruction and return the number of cycles it took, or
+    /// 0 if the program has ended
+    fn step(&mut self) -> usize {
+        self.with_cpu(year2022::day10::Cpu::step)
+    }
+
+    /// Run the rest of the program and return the sum of signal strengths
+    /// and the CRT output
+    fn run(&mut self) -> (i32, String) {
+        self.with_cpu(year2022::day10::Cpu::run)
+    }
+}
+
+/// Define a submodule for each given day, with a function answering a part
+/// of its puzzle and the given classes
+macro_rules! day_modules {
+    ($year:literal: $($name:ident => $day:literal $([$($class:ty),*])?),* $(,)?) => {
+        $(
+            mod $name {
+                use super::*;
+
+                #[doc = concat!("Answer the given part of ", $year, " day ", $day, " for the given input")]
+                #[pyfunction]
+                pub(super) fn solve(input: &str, part: u8) -> PyResult<Answer> {
+                    super::solve($year, $day, part, input)
+                }
+            }
+        )*
+
+        /// Add the submodules of all days to the given module. Submodules
+        /// are registered in `sys.modules` to make them importable.
+        fn add_day_modules(module: &Bound<'_, PyModule>) -> PyResult<()> {
+            let modules = module.py().import("sys")?.getattr("modules")?;
+            $(
+                let day = PyModule::new(module.py(), stringify!($name))?;
+                day.add_function(wrap_pyfunction!($name::solve, &day)?)?;
+                $($(day.add_class::<$class>()?;)*)?
+                module.add_submodule(&day)?;
+                modules.set_item(format!("{}.{}", module.name()?, stringify!($name)), &day)?;
+            )*
+            Ok(())
+        }
+    };
+}
+
+day_modules!(2022:
+    day01 => 1,
+    day02 => 2,
+    day03 => 3,
+    day04 => 4,
+    day05 => 5,
+    day06 => 6,
+    day07 => 7 [Filesystem],
+    day08 => 8,
+    day09 => 9,
+    day10 => 10 [Cpu],
+);
+
+/// Solutions to the Advent of Code 2022 puzzles
+#[pymodule]
+fn advent_of_code_2022(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(days, module)?)?;
+    module.add_function(wrap_pyfunction!(solve, module)?)?;
+    add_day_modules(module)
+}
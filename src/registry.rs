@@ -0,0 +1,194 @@
+//! Advent of Code: registry of puzzle solutions
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::input;
+use crate::{year2022, Answer, Error, Input, Result, Solution, Solver};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{io, io::Read, path::PathBuf};
+use thiserror::Error;
+
+/// Type-erased parse function of a puzzle solution
+type ParseFn = fn(Input<&[u8]>) -> Result<Box<dyn Solver>>;
+
+/// Input generator function of a puzzle solution
+type GenerateFn = fn(&mut dyn RngCore, usize) -> Option<String>;
+
+/// Failure to answer a part of a registered puzzle, distinguishing unknown
+/// puzzles and parts for bindings to report them separately
+#[derive(Debug, Error)]
+pub enum SolveError {
+    /// No solution registered for the puzzle
+    #[error("no puzzle {year} day {day}")]
+    UnknownPuzzle { year: u16, day: usize },
+    /// Solution doesn't answer the part
+    #[error("no part {part} in {year} day {day}")]
+    UnknownPart { year: u16, day: usize, part: u8 },
+    /// Parsing the input failed
+    #[error(transparent)]
+    Parse(Error),
+    /// Answering the part failed
+    #[error(transparent)]
+    Solve(Error),
+}
+
+/// Registered puzzle solution
+#[derive(Debug, Clone, Copy)]
+pub struct Puzzle {
+    /// Event year
+    pub year: u16,
+    /// Day of puzzle
+    pub day: usize,
+    parse: ParseFn,
+    generate: GenerateFn,
+}
+
+impl Puzzle {
+    /// Register solution for the given puzzle
+    #[must_use]
+    pub const fn new<S: Solution + 'static>(year: u16, day: usize) -> Self {
+        Self {
+            year,
+            day,
+            parse: parse_boxed::<S>,
+            generate: S::generate,
+        }
+    }
+
+    /// Open puzzle input for this puzzle
+    ///
+    /// # Errors
+    /// I/O error, decryption failure or checksum mismatch
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn input(&self) -> io::Result<Input<Box<dyn Read>>> {
+        Input::year_day(self.year, self.day)
+    }
+
+    /// Find example input files for this puzzle (`fixtures/<year>/dayNN.txt`
+    /// and variants like `dayNN-2.txt`)
+    ///
+    /// # Errors
+    /// I/O error
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn examples(&self) -> io::Result<Vec<PathBuf>> {
+        input::example_files(self.year, self.day)
+    }
+
+    /// Parse the given puzzle input
+    ///
+    /// # Errors
+    /// I/O or parse error
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Solver>> {
+        (self.parse)(Input::from(input))
+    }
+
+    /// Answer the given part with the given solver of this puzzle
+    ///
+    /// # Errors
+    /// Unknown part or failure to answer the part
+    pub fn answer(&self, solver: &dyn Solver, part: u8) -> Result<Answer, SolveError> {
+        let (year, day) = (self.year, self.day);
+        solver
+            .solve(part)
+            .ok_or(SolveError::UnknownPart { year, day, part })?
+            .map_err(SolveError::Solve)
+    }
+
+    /// Answer the given part of this puzzle for the given input
+    ///
+    /// # Errors
+    /// Parse error, unknown part or failure to answer the part
+    pub fn solve(&self, part: u8, input: &str) -> Result<Answer, SolveError> {
+        let solver = self.parse(input).map_err(SolveError::Parse)?;
+        self.answer(solver.as_ref(), part)
+    }
+
+    /// Generate a random puzzle input of roughly the given size. The same
+    /// seed always generates the same input. Returns `None` if the solution
+    /// doesn't support generating inputs.
+    #[must_use]
+    pub fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        (self.generate)(&mut StdRng::seed_from_u64(seed), size)
+    }
+}
+
+fn parse_boxed<S: Solution + 'static>(input: Input<&[u8]>) -> Result<Box<dyn Solver>> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+/// Puzzle solutions of all years
+const YEARS: &[&[Puzzle]] = &[year2022::PUZZLES];
+
+/// Iterator over all registered puzzles, ordered by year and day
+pub fn puzzles() -> impl Iterator<Item = &'static Puzzle> {
+    YEARS.iter().flat_map(|puzzles| puzzles.iter())
+}
+
+/// Iterator over registered puzzles of the given year
+pub fn year(year: u16) -> impl Iterator<Item = &'static Puzzle> {
+    puzzles().filter(move |p| p.year == year)
+}
+
+/// Find registered puzzle for the given year and day
+#[must_use]
+pub fn find(year: u16, day: usize) -> Option<&'static Puzzle> {
+    puzzles().find(|p| p.year == year && p.day == day)
+}
+
+/// Answer the given part of the registered puzzle for the given year and
+/// day for the given input
+///
+/// # Errors
+/// Unknown puzzle or part, parse error or failure to answer the part
+pub fn solve(year: u16, day: usize, part: u8, input: &str) -> Result<Answer, SolveError> {
+    find(year, day)
+        .ok_or(SolveError::UnknownPuzzle { year, day })?
+        .solve(part, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_puzzle() {
+        let puzzle = find(2022, 1).unwrap();
+        assert_eq!((puzzle.year, puzzle.day), (2022, 1));
+        assert!(find(2022, 26).is_none());
+        assert!(find(2015, 1).is_none());
+    }
+
+    #[test]
+    fn parse_and_solve() {
+        let solver = find(2022, 1).unwrap().parse("1\n2\n\n4\n").unwrap();
+        assert_eq!(solver.solve(1).unwrap().unwrap(), 4_u32.into());
+        assert_eq!(solver.solve(2).unwrap().unwrap(), 7_u32.into());
+        assert!(solver.solve(3).is_none());
+    }
+
+    #[test]
+    fn solve_by_year_and_day() {
+        let answer = solve(2022, 1, 2, "1\n2\n\n4\n").unwrap();
+        assert_eq!(answer, 7_u32.into());
+        let err = solve(2022, 26, 1, "").unwrap_err();
+        assert!(matches!(err, SolveError::UnknownPuzzle { .. }));
+        assert_eq!(err.to_string(), "no puzzle 2022 day 26");
+        let err = solve(2022, 1, 3, "1\n").unwrap_err();
+        assert!(matches!(err, SolveError::UnknownPart { .. }));
+        assert_eq!(err.to_string(), "no part 3 in 2022 day 1");
+        assert!(matches!(solve(2022, 1, 1, "x"), Err(SolveError::Parse(_))));
+    }
+
+    #[test]
+    fn generate_inputs() {
+        for puzzle in puzzles() {
+            for seed in 0..5 {
+                let input = puzzle.generate(seed, 50).unwrap();
+                assert_eq!(puzzle.generate(seed, 50).unwrap(), input);
+                let solver = puzzle.parse(&input).unwrap();
+                assert!(solver.solve(1).unwrap().is_ok());
+                assert!(solver.solve(2).unwrap().is_ok());
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Advent of Code: timed puzzle runs
+
+use crate::{Answer, Puzzle, Result};
+use std::time::{Duration, Instant};
+
+/// Answer to one part of a puzzle and the time it took
+#[derive(Debug)]
+pub struct PartRun {
+    /// Part of the puzzle (1 or 2)
+    pub part: u8,
+    /// Answer or error
+    pub answer: Result<Answer>,
+    /// Time taken to answer
+    pub duration: Duration,
+}
+
+/// Timed run of parsing a puzzle input and answering all parts
+#[derive(Debug)]
+pub struct Run {
+    /// Time taken to parse the input
+    pub parse_duration: Duration,
+    /// Answers to all parts
+    pub parts: Vec<PartRun>,
+}
+
+impl Run {
+    /// Parse the given input and answer all parts of the given puzzle
+    ///
+    /// # Errors
+    /// I/O or parse error
+    pub fn new(puzzle: &Puzzle, input: &str) -> Result<Self> {
+        Self::with_parts(puzzle, input, 1..)
+    }
+
+    /// Parse the given input and answer only the given part of the given
+    /// puzzle. There's no answer if the part doesn't exist.
+    ///
+    /// # Errors
+    /// I/O or parse error
+    pub fn part(puzzle: &Puzzle, input: &str, part: u8) -> Result<Self> {
+        Self::with_parts(puzzle, input, part..=part)
+    }
+
+    /// Parse the given input and answer the given parts, up to the first one
+    /// that doesn't exist
+    fn with_parts<I>(puzzle: &Puzzle, input: &str, parts: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let start = Instant::now();
+        let solver = puzzle.parse(input)?;
+        let parse_duration = start.elapsed();
+        let parts = parts
+            .into_iter()
+            .map_while(|part| {
+                let start = Instant::now();
+                let answer = solver.solve(part)?;
+                Some(PartRun {
+                    part,
+                    answer,
+                    duration: start.elapsed(),
+                })
+            })
+            .collect();
+        Ok(Self {
+            parse_duration,
+            parts,
+        })
+    }
+
+    /// Total time taken to parse and answer all parts
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.parse_duration
+            + self
+                .parts
+                .iter()
+                .map(|part| part.duration)
+                .sum::<Duration>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn run_puzzle() {
+        let puzzle = registry::find(2022, 1).unwrap();
+        let run = Run::new(puzzle, "1000\n2000\n\n4000\n\n5000\n6000\n").unwrap();
+        let answers: Vec<_> = run
+            .parts
+            .iter()
+            .map(|part| part.answer.as_ref().unwrap().clone())
+            .collect();
+        assert_eq!(answers, [11000_u32.into(), 18000_u32.into()]);
+        assert!(run.duration() >= run.parse_duration);
+    }
+
+    #[test]
+    fn run_part() {
+        let puzzle = registry::find(2022, 1).unwrap();
+        let run = Run::part(puzzle, "1000\n2000\n\n4000\n", 2).unwrap();
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].part, 2);
+        assert_eq!(*run.parts[0].answer.as_ref().unwrap(), 7000_u32.into());
+        assert!(Run::part(puzzle, "1000\n", 3).unwrap().parts.is_empty());
+    }
+
+    #[test]
+    fn parse_error() {
+        let puzzle = registry::find(2022, 1).unwrap();
+        assert!(Run::new(puzzle, "x\n").is_err());
+    }
+}
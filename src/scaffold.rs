@@ -0,0 +1,319 @@
+//! Advent of Code: scaffolding for new puzzle solutions
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Source root of this crate
+const ROOT_PATH: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Template of a new solution module
+const MODULE_TEMPLATE: &str = r#"//! Day {day}
+
+use crate::{Error, Input, Result, Solution};
+use itertools::Itertools;
+use std::io::Read;
+
+/// Puzzle notes
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct Notes(Vec<String>);
+
+impl Solution for Notes {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        Ok(Self(input.lines().try_collect()?))
+    }
+
+    fn part_1(&self) -> Result<usize> {
+        Err(Error::unsolvable("part 1 not implemented yet"))
+    }
+
+    fn part_2(&self) -> Result<usize> {
+        Err(Error::unsolvable("part 2 not implemented yet"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notes() -> Notes {
+        const INPUT: &str = include_str!("../../fixtures/{year}/day{day:02}.txt");
+        Notes::parse(Input::from(INPUT)).unwrap()
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(notes().part_1().unwrap(), 0);
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(notes().part_2().unwrap(), 0);
+    }
+}
+"#;
+
+/// Template of a new year module
+const YEAR_TEMPLATE: &str = r"//! Advent of Code {year}
+
+use crate::Puzzle;
+
+/// Puzzle solutions of {year}
+pub const PUZZLES: &[Puzzle] = &[];
+";
+
+/// Declaration of registry entries in a year module
+const PUZZLES_DECL: &str = "pub const PUZZLES: &[Puzzle] = &[";
+
+/// Fill in year and day in the given template
+fn render(template: &str, year: u16, day: usize) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day:02}", &format!("{day:02}"))
+        .replace("{day}", &day.to_string())
+}
+
+/// Insert a line into the block of lines starting with the given prefix,
+/// keeping the block sorted. If there's no such block, the line is inserted
+/// before the first line starting with `before`.
+fn insert_sorted(source: &str, prefix: &str, before: &str, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let pos = match lines.iter().rposition(|l| l.starts_with(prefix)) {
+        Some(last) => lines
+            .iter()
+            .position(|l| l.starts_with(prefix) && *l > line)
+            .unwrap_or(last + 1),
+        None => lines.iter().position(|l| l.starts_with(before))?,
+    };
+    lines.insert(pos, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Add an item to the comma separated list between the given delimiters,
+/// sorted like rustfmt sorts imports (lowercase before uppercase)
+fn insert_item(source: &str, start: &str, end: &str, item: &str) -> Option<String> {
+    let from = source.find(start)? + start.len();
+    let to = from + source[from..].find(end)?;
+    let mut items: Vec<&str> = source[from..to].split(", ").collect();
+    items.push(item);
+    items.sort_by_key(|item| (item.starts_with(char::is_uppercase), *item));
+    Some(format!(
+        "{}{}{}",
+        &source[..from],
+        items.join(", "),
+        &source[to..]
+    ))
+}
+
+/// Split registry entries that rustfmt put on a single line into one entry
+/// per line
+fn expand_entries(source: &str) -> String {
+    let mut lines = Vec::new();
+    for line in source.lines() {
+        match line
+            .strip_prefix(PUZZLES_DECL)
+            .and_then(|entries| entries.strip_suffix("];"))
+        {
+            Some(entries) => {
+                lines.push(PUZZLES_DECL.to_string());
+                for entry in entries.split("), ").filter(|e| !e.is_empty()) {
+                    lines.push(format!("    {}),", entry.trim_end_matches(')')));
+                }
+                lines.push("];".to_string());
+            }
+            None => lines.push(line.to_string()),
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Put a single registry entry on one line like rustfmt does
+fn collapse_entries(source: &str) -> String {
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    if let Some(i) = lines.iter().position(|line| line == PUZZLES_DECL) {
+        if lines.get(i + 2).is_some_and(|line| line == "];") {
+            let entry = lines[i + 1].trim().trim_end_matches(',');
+            let decl = format!("{PUZZLES_DECL}{entry}];");
+            lines.splice(i..i + 3, [decl]);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Add module declaration and registry entry for a new day to the given
+/// year module source
+fn register_day(source: &str, year: u16, day: usize) -> Option<String> {
+    let module = format!("pub mod day{day:02};");
+    let source = if source.contains("\npub mod day") {
+        insert_sorted(source, "pub mod day", "", &module)?
+    } else {
+        let module = module + "\n";
+        insert_sorted(source, "pub mod day", "/// Puzzle solutions", &module)?
+    };
+    let entry = format!("    Puzzle::new::<day{day:02}::Notes>({year}, {day}),");
+    let source = insert_sorted(&expand_entries(&source), "    Puzzle::new::", "];", &entry)?;
+    Some(collapse_entries(&source))
+}
+
+/// Add module declaration and registry entry for a new year to the given
+/// library and registry sources
+fn register_year(lib: &str, registry: &str, year: u16) -> Option<(String, String)> {
+    let lib = insert_sorted(lib, "pub mod year", "", &format!("pub mod year{year};"))?;
+    let registry = insert_item(registry, "use crate::{", "}", &format!("year{year}"))?;
+    let registry = insert_item(
+        &registry,
+        "const YEARS: &[&[Puzzle]] = &[",
+        "];",
+        &format!("year{year}::PUZZLES"),
+    )?;
+    Some((lib, registry))
+}
+
+/// Create solution module, example fixture and registry entry for the given
+/// day. Returns the paths of created files.
+///
+/// # Errors
+/// I/O error or the day already exists
+pub fn generate(year: u16, day: usize) -> io::Result<Vec<PathBuf>> {
+    generate_in(Path::new(ROOT_PATH), year, day)
+}
+
+fn generate_in(root: &Path, year: u16, day: usize) -> io::Result<Vec<PathBuf>> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg);
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Day must be between 1 and 25",
+        ));
+    }
+    let year_dir = root.join(format!("src/year{year}"));
+    let module = year_dir.join(format!("day{day:02}.rs"));
+    let fixture = root.join(format!("fixtures/{year}/day{day:02}.txt"));
+    for path in [&module, &fixture] {
+        if path.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", path.display()),
+            ));
+        }
+    }
+
+    let mut created = Vec::new();
+    let year_module = year_dir.join("mod.rs");
+    if !year_module.exists() {
+        let lib_path = root.join("src/lib.rs");
+        let registry_path = root.join("src/registry.rs");
+        let (lib, registry) = register_year(
+            &fs::read_to_string(&lib_path)?,
+            &fs::read_to_string(&registry_path)?,
+            year,
+        )
+        .ok_or_else(|| invalid("Unable to register year"))?;
+        fs::create_dir_all(&year_dir)?;
+        fs::write(&year_module, render(YEAR_TEMPLATE, year, day))?;
+        fs::write(lib_path, lib)?;
+        fs::write(registry_path, registry)?;
+        created.push(year_module.clone());
+    }
+    let source = register_day(&fs::read_to_string(&year_module)?, year, day)
+        .ok_or_else(|| invalid("Unable to register day"))?;
+    fs::write(&year_module, source)?;
+
+    fs::write(&module, render(MODULE_TEMPLATE, year, day))?;
+    created.push(module);
+    fs::create_dir_all(fixture.parent().unwrap_or(root))?;
+    fs::write(&fixture, "")?;
+    created.push(fixture);
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tempdir::TempDir;
+
+    const YEAR_MODULE: &str = "//! Advent of Code 2022
+
+use crate::Puzzle;
+
+pub mod day01;
+pub mod day02;
+
+/// Puzzle solutions of 2022
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<day01::Calories>(2022, 1),
+    Puzzle::new::<day02::StrategyGuide>(2022, 2),
+];
+";
+
+    #[test]
+    fn render_template() {
+        let module = render(MODULE_TEMPLATE, 2022, 7);
+        assert!(module.starts_with("//! Day 7\n"));
+        assert!(module.contains("\"../../fixtures/2022/day07.txt\""));
+    }
+
+    #[test]
+    fn register_existing_year() {
+        let source = register_day(YEAR_MODULE, 2022, 3).unwrap();
+        assert!(source.contains("pub mod day02;\npub mod day03;\n\n/// Puzzle solutions"));
+        assert!(source.contains("(2022, 2),\n    Puzzle::new::<day03::Notes>(2022, 3),\n];"));
+    }
+
+    #[test]
+    fn register_new_year() {
+        let source = register_day(&render(YEAR_TEMPLATE, 2023, 1), 2023, 1).unwrap();
+        assert_eq!(
+            source,
+            "//! Advent of Code 2023
+
+use crate::Puzzle;
+
+pub mod day01;
+
+/// Puzzle solutions of 2023
+pub const PUZZLES: &[Puzzle] = &[Puzzle::new::<day01::Notes>(2023, 1)];
+"
+        );
+        let source = register_day(&source, 2023, 2).unwrap();
+        assert!(source.ends_with(
+            "&[
+    Puzzle::new::<day01::Notes>(2023, 1),
+    Puzzle::new::<day02::Notes>(2023, 2),
+];
+"
+        ));
+        let lib = "mod input;\n\npub mod year2022;\n";
+        let registry = "use crate::{year2022, Input};\n\
+            const YEARS: &[&[Puzzle]] = &[year2022::PUZZLES];\n";
+        let (lib, registry) = register_year(lib, registry, 2023).unwrap();
+        assert_eq!(lib, "mod input;\n\npub mod year2022;\npub mod year2023;\n");
+        assert_eq!(
+            registry,
+            "use crate::{year2022, year2023, Input};\n\
+            const YEARS: &[&[Puzzle]] = &[year2022::PUZZLES, year2023::PUZZLES];\n"
+        );
+    }
+
+    #[test]
+    fn generate_day() {
+        let root = TempDir::new("scaffold");
+        fs::create_dir_all(root.join("src/year2022")).unwrap();
+        fs::write(root.join("src/year2022/mod.rs"), YEAR_MODULE).unwrap();
+        let created = generate_in(&root, 2022, 3).unwrap();
+        assert_eq!(
+            created,
+            [
+                root.join("src/year2022/day03.rs"),
+                root.join("fixtures/2022/day03.txt")
+            ]
+        );
+        let err = generate_in(&root, 2022, 3).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert!(generate_in(&root, 2022, 26).is_err());
+    }
+}
@@ -0,0 +1,330 @@
+//! Advent of Code: graph search
+//!
+//! Breadth-first search, Dijkstra and A* over graphs given by a neighbor
+//! function. Nodes can be anything hashable, e.g. grid positions or whole
+//! puzzle states. [`grid4`] and [`grid8`] create neighbor functions for
+//! grids of [`Point2`].
+
+use crate::geometry::{BoundingBox, Coordinate, Point2};
+use std::cmp::Ordering;
+use std::collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Shortest distances and predecessors of nodes reached by a search
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Distance of the given node from the nearest start node, `None` if
+    /// it wasn't reached
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// Distances of all reached nodes
+    #[must_use]
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// Previous node on a shortest path to the given node, `None` for start
+    /// nodes and nodes that weren't reached
+    pub fn predecessor(&self, node: &N) -> Option<&N> {
+        self.predecessors.get(node)
+    }
+
+    /// Predecessors of all reached nodes except start nodes
+    #[must_use]
+    pub fn predecessors(&self) -> &HashMap<N, N> {
+        &self.predecessors
+    }
+
+    /// Shortest path from a start node to the given node (both included),
+    /// `None` if it wasn't reached
+    pub fn path(&self, target: &N) -> Option<Vec<N>> {
+        self.distances.get(target)?;
+        let mut path = vec![target.clone()];
+        while let Some(node) = self.predecessor(path.last()?) {
+            path.push(node.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from the given start nodes, with all edges having
+/// length 1
+pub fn bfs<N, S, F, I>(starts: S, mut neighbors: F) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbors(&node) {
+            if let Entry::Vacant(entry) = paths.distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                paths.predecessors.insert(next.clone(), node.clone());
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    paths
+}
+
+/// Node queued for a search, ordered by lowest priority first
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Best-first search expanding nodes by cost plus heuristic, stopping at
+/// the first goal node
+fn best_first<N, C, S, F, I, H, G>(
+    starts: S,
+    mut neighbors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> (Paths<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Coordinate,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut paths = Paths::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        paths.distances.insert(start.clone(), C::default());
+        queue.push(Queued {
+            priority: heuristic(&start),
+            cost: C::default(),
+            node: start,
+        });
+    }
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if paths.distance(&node).is_some_and(|best| best < cost) {
+            // Already expanded with a lower cost
+            continue;
+        }
+        if is_goal(&node) {
+            return (paths, Some(node));
+        }
+        for (next, edge) in neighbors(&node) {
+            let cost = cost + edge;
+            if paths.distance(&next).is_some_and(|best| best <= cost) {
+                continue;
+            }
+            paths.distances.insert(next.clone(), cost);
+            paths.predecessors.insert(next.clone(), node.clone());
+            queue.push(Queued {
+                priority: cost + heuristic(&next),
+                cost,
+                node: next,
+            });
+        }
+    }
+    (paths, None)
+}
+
+/// Dijkstra's search from the given start nodes. The neighbor function
+/// returns neighbors with the (non-negative) length of the edge to them.
+pub fn dijkstra<N, C, S, F, I>(starts: S, neighbors: F) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Coordinate,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(starts, neighbors, |_| C::default(), |_| false).0
+}
+
+/// A* search from the given start nodes to the nearest goal node. The
+/// heuristic must not overestimate the distance to the nearest goal.
+/// Returns the shortest path (including start and goal) and its length.
+pub fn astar<N, C, S, F, I, H, G>(
+    starts: S,
+    neighbors: F,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Coordinate,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let (paths, goal) = best_first(starts, neighbors, heuristic, is_goal);
+    let goal = goal?;
+    Some((paths.path(&goal)?, paths.distance(&goal)?))
+}
+
+/// Neighbor function for a grid with 4-neighborhood, restricted to the
+/// given bounds and to moves allowed by `can_move(from, to)`
+pub fn grid4<T, M>(
+    bounds: BoundingBox<Point2<T>>,
+    can_move: M,
+) -> impl Fn(&Point2<T>) -> Vec<Point2<T>>
+where
+    T: Coordinate,
+    M: Fn(Point2<T>, Point2<T>) -> bool,
+{
+    move |&from| {
+        from.neighbors()
+            .filter(|&to| bounds.contains(to) && can_move(from, to))
+            .collect()
+    }
+}
+
+/// Neighbor function for a grid with 8-neighborhood (including diagonals),
+/// restricted to the given bounds and to moves allowed by `can_move(from,
+/// to)`
+pub fn grid8<T, M>(
+    bounds: BoundingBox<Point2<T>>,
+    can_move: M,
+) -> impl Fn(&Point2<T>) -> Vec<Point2<T>>
+where
+    T: Coordinate,
+    M: Fn(Point2<T>, Point2<T>) -> bool,
+{
+    move |&from| {
+        from.neighbors8()
+            .filter(|&to| bounds.contains(to) && can_move(from, to))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Maze with walls (`#`), start (`S`) and end (`E`)
+    const MAZE: &str = "\
+        S..#....\n\
+        .#.#.##.\n\
+        .#...#..\n\
+        .####.#.\n\
+        ......#E";
+
+    fn maze() -> (BoundingBox<Point2<usize>>, impl Fn(Point2<usize>) -> char) {
+        let rows: Vec<Vec<char>> = MAZE.lines().map(|line| line.chars().collect()).collect();
+        let bounds = BoundingBox::from_points([
+            Point2::new(0, 0),
+            Point2::new(rows[0].len() - 1, rows.len() - 1),
+        ])
+        .unwrap();
+        (bounds, move |p: Point2<usize>| rows[p.y][p.x])
+    }
+
+    #[test]
+    fn bfs_grid() {
+        let (bounds, tile) = maze();
+        let neighbors = grid4(bounds, |_, to| tile(to) != '#');
+        let paths = bfs([Point2::new(0, 0)], neighbors);
+        let end = Point2::new(7, 4);
+        assert_eq!(paths.distance(&end), Some(15));
+        assert_eq!(paths.distance(&Point2::new(1, 1)), None);
+        let path = paths.path(&end).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!(path[0], Point2::new(0, 0));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+        assert_eq!(paths.predecessor(&Point2::new(0, 0)), None);
+    }
+
+    #[test]
+    fn diagonal_moves() {
+        let (bounds, tile) = maze();
+        let neighbors = grid8(bounds, |_, to| tile(to) != '#');
+        let paths = bfs([Point2::new(0, 0)], neighbors);
+        assert_eq!(paths.distance(&Point2::new(7, 4)), Some(8));
+    }
+
+    #[test]
+    fn dijkstra_weighted() {
+        // a -1-> b -1-> c, a -5-> c, c -1-> d
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+        let paths = dijkstra(['a'], edges);
+        assert_eq!(paths.distance(&'c'), Some(2));
+        assert_eq!(paths.path(&'d').unwrap(), ['a', 'b', 'c', 'd']);
+        assert_eq!(paths.distances().len(), 4);
+        assert_eq!(paths.path(&'x'), None);
+    }
+
+    #[test]
+    fn astar_grid() {
+        let (bounds, tile) = maze();
+        let end = Point2::new(7, 4);
+        let neighbors = grid4(bounds, |_, to| tile(to) != '#');
+        let (path, length) = astar(
+            [Point2::new(0, 0)],
+            |p| neighbors(p).into_iter().map(|q| (q, 1)),
+            |p| p.manhattan(end),
+            |p| *p == end,
+        )
+        .unwrap();
+        assert_eq!(length, 15);
+        assert_eq!(path.len(), 16);
+        assert_eq!(path.last(), Some(&end));
+        let unreachable = astar([0_u32], |_| [], |_| 0_u32, |n| *n == 1);
+        assert!(unreachable.is_none());
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let neighbors = |n: &i32| [n - 1, n + 1].into_iter().filter(|n| (0..=10).contains(n));
+        let paths = bfs([0, 10], neighbors);
+        assert_eq!(paths.distance(&5), Some(5));
+        assert_eq!(paths.distance(&8), Some(2));
+    }
+}
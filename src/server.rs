@@ -0,0 +1,400 @@
+//! Advent of Code: HTTP solving service
+//!
+//! Serves puzzle solutions over HTTP, so that they can be used without
+//! linking Rust code. Endpoints:
+//! - `GET /puzzles`: list registered puzzles
+//! - `POST /puzzles/<year>/<day>/<part>`: answer a part of a puzzle for the
+//!   input given as request body
+//!
+//! Responses are JSON. A fixed number of worker threads handle one request
+//! per connection each.
+
+use crate::registry::{self, SolveError};
+use crate::{Answer, Result};
+use std::fmt::Write as _;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Maximum size of request line and headers
+const MAX_HEAD_SIZE: u64 = 8 * 1024;
+
+/// Default maximum size of a request body (puzzle input)
+const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// Timeout for reading a request and writing the response
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// HTTP request
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+/// HTTP response with JSON body
+#[derive(Debug, PartialEq, Eq)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn ok(body: String) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: format!("{{\"error\":{}}}", json_string(message)),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            411 => "Length Required",
+            413 => "Content Too Large",
+            422 => "Unprocessable Content",
+            431 => "Request Header Fields Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+
+    fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+            Connection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body
+        )?;
+        writer.flush()
+    }
+}
+
+/// Quote and escape the given string as JSON string
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if ch.is_control() => {
+                let _ = write!(json, "\\u{:04x}", u32::from(ch));
+            }
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// JSON object of the given answer, with its type and value. Images are
+/// given as text with lines of `#` and `.`.
+fn answer_json(answer: &Answer) -> String {
+    let (kind, value) = match answer {
+        Answer::Integer(n) => ("integer", n.to_string()),
+        Answer::Text(s) => ("text", json_string(s)),
+        Answer::Image(_) => ("image", json_string(&answer.to_string())),
+    };
+    format!("{{\"type\":\"{kind}\",\"value\":{value}}}")
+}
+
+/// Read a request from the given reader. A response describing the problem
+/// is returned for invalid requests.
+fn read_request<R: BufRead>(mut reader: R, max_body_size: usize) -> Result<Request, Response> {
+    let bad_request = |_| Response::error(400, "invalid request");
+    let mut head = (&mut reader).take(MAX_HEAD_SIZE);
+    let mut line = String::new();
+    head.read_line(&mut line).map_err(bad_request)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target), Some(_version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(Response::error(400, "invalid request line"));
+    };
+    let (method, path) = (method.to_string(), target.split('?').next().unwrap_or("/"));
+    let path = path.to_string();
+    let mut content_length = None;
+    loop {
+        line.clear();
+        head.read_line(&mut line).map_err(bad_request)?;
+        if !line.ends_with('\n') {
+            return Err(match head.limit() {
+                0 => Response::error(431, "request head too large"),
+                _ => Response::error(400, "incomplete request"),
+            });
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(Response::error(400, "invalid header"));
+        };
+        if name.eq_ignore_ascii_case("content-length") {
+            let length = value.trim().parse::<usize>();
+            content_length = Some(length.map_err(|_| Response::error(400, "invalid length"))?);
+        } else if name.eq_ignore_ascii_case("transfer-encoding") {
+            return Err(Response::error(411, "content length required"));
+        }
+    }
+    let body = match content_length {
+        Some(length) if length > max_body_size => {
+            let message = format!("request body exceeds {max_body_size} bytes");
+            return Err(Response::error(413, &message));
+        }
+        Some(length) => {
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).map_err(bad_request)?;
+            body
+        }
+        None if method == "POST" => return Err(Response::error(411, "content length required")),
+        None => Vec::new(),
+    };
+    Ok(Request { method, path, body })
+}
+
+/// List registered puzzles
+fn list() -> Response {
+    let puzzles = registry::puzzles()
+        .map(|puzzle| format!("{{\"year\":{},\"day\":{}}}", puzzle.year, puzzle.day))
+        .collect::<Vec<_>>()
+        .join(",");
+    Response::ok(format!("{{\"puzzles\":[{puzzles}]}}"))
+}
+
+/// Answer the given part of a puzzle for the given input
+fn solve(year: &str, day: &str, part: &str, input: &[u8]) -> Response {
+    let (Ok(year), Ok(day), Ok(part)) = (year.parse(), day.parse(), part.parse::<u8>()) else {
+        return Response::error(404, "not found");
+    };
+    let Some(puzzle) = registry::find(year, day) else {
+        return Response::error(404, "no such puzzle");
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return Response::error(400, "input is not valid UTF-8");
+    };
+    let res = panic::catch_unwind(AssertUnwindSafe(|| -> Result<_, SolveError> {
+        let start = Instant::now();
+        let solver = puzzle.parse(input).map_err(SolveError::Parse)?;
+        let parse_duration = start.elapsed();
+        let start = Instant::now();
+        let answer = puzzle.answer(solver.as_ref(), part)?;
+        Ok((answer, parse_duration, start.elapsed()))
+    }));
+    match res {
+        Ok(Ok((answer, parse_duration, solve_duration))) => Response::ok(format!(
+            "{{\"year\":{year},\"day\":{day},\"part\":{part},\"answer\":{},\
+            \"parse_us\":{},\"solve_us\":{}}}",
+            answer_json(&answer),
+            parse_duration.as_micros(),
+            solve_duration.as_micros()
+        )),
+        Ok(Err(SolveError::UnknownPuzzle { .. })) => Response::error(404, "no such puzzle"),
+        Ok(Err(SolveError::UnknownPart { .. })) => Response::error(404, "no such part"),
+        Ok(Err(SolveError::Parse(err) | SolveError::Solve(err))) => {
+            Response::error(422, &err.to_string())
+        }
+        Err(_) => Response::error(500, "solver panicked"),
+    }
+}
+
+/// Route the given request to its endpoint
+fn handle(request: &Request) -> Response {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    match (request.method.as_str(), &segments[..]) {
+        ("GET", ["puzzles"]) => list(),
+        ("POST", ["puzzles", year, day, part]) => solve(year, day, part, &request.body),
+        (_, ["puzzles"] | ["puzzles", _, _, _]) => Response::error(405, "method not allowed"),
+        _ => Response::error(404, "not found"),
+    }
+}
+
+/// HTTP server answering puzzles
+#[derive(Debug)]
+pub struct Server {
+    listener: TcpListener,
+    max_body_size: usize,
+}
+
+impl Server {
+    /// Create server listening on the given address
+    ///
+    /// # Errors
+    /// I/O error if the address can't be bound
+    pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+        })
+    }
+
+    /// Set the maximum size of request bodies (default 1 MiB). Larger
+    /// requests are rejected.
+    #[must_use]
+    pub fn max_body_size(mut self, size: usize) -> Self {
+        self.max_body_size = size;
+        self
+    }
+
+    /// Address the server is listening on
+    ///
+    /// # Errors
+    /// I/O error
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Handle requests with the given number of worker threads. Never
+    /// returns.
+    pub fn run(&self, workers: usize) {
+        thread::scope(|scope| {
+            for _ in 0..workers.max(1) {
+                scope.spawn(|| loop {
+                    match self.listener.accept() {
+                        Ok((stream, _)) => {
+                            // Errors only affect the client of this connection
+                            let _ = self.handle_connection(&stream);
+                        }
+                        Err(e) => eprintln!("Warning: failed to accept connection: {e}"),
+                    }
+                });
+            }
+        });
+    }
+
+    fn handle_connection(&self, stream: &TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+        match read_request(BufReader::new(stream), self.max_body_size) {
+            Ok(request) => handle(&request).write_to(stream),
+            Err(response) => {
+                response.write_to(stream)?;
+                // Closing the connection with unread data would reset it,
+                // possibly before the client read the response
+                stream.shutdown(Shutdown::Write)?;
+                let mut unread = stream.take(self.max_body_size as u64 + MAX_HEAD_SIZE);
+                io::copy(&mut unread, &mut io::sink())?;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Start a server on a free port of localhost
+    fn start(max_body_size: usize) -> SocketAddr {
+        let server = Server::bind("127.0.0.1:0")
+            .unwrap()
+            .max_body_size(max_body_size);
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || server.run(4));
+        addr
+    }
+
+    /// Send the given raw request and return status and body of the response
+    fn request(addr: SocketAddr, request: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, body.to_string())
+    }
+
+    fn post(addr: SocketAddr, path: &str, input: &str) -> (u16, String) {
+        let len = input.len();
+        request(
+            addr,
+            &format!("POST {path} HTTP/1.1\r\nContent-Length: {len}\r\n\r\n{input}"),
+        )
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(json_string("a \"b\"\n\\"), r#""a \"b\"\n\\""#);
+        assert_eq!(json_string("\u{1}"), r#""\u0001""#);
+        assert_eq!(
+            answer_json(&Answer::Integer(42)),
+            r#"{"type":"integer","value":42}"#
+        );
+        assert_eq!(
+            answer_json(&vec![vec![true, false], vec![false, true]].into()),
+            r##"{"type":"image","value":"#.\n.#"}"##
+        );
+    }
+
+    #[test]
+    fn endpoints() {
+        let addr = start(1024);
+        let (status, body) = request(addr, "GET /puzzles HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 200);
+        assert!(body.starts_with(r#"{"puzzles":[{"year":2022,"day":1},"#));
+        let (status, body) = post(addr, "/puzzles/2022/1/2", "1\n2\n\n4\n");
+        assert_eq!(status, 200);
+        assert!(body.starts_with(
+            r#"{"year":2022,"day":1,"part":2,"answer":{"type":"integer","value":7},"parse_us":"#
+        ));
+        assert!(body.contains(r#","solve_us":"#));
+        let (status, body) = post(addr, "/puzzles/2022/5/1", "");
+        assert_eq!(status, 422);
+        assert_eq!(body, r#"{"error":"Parse error: missing drawing"}"#);
+        assert_eq!(post(addr, "/puzzles/2022/1/3", "1\n").0, 404);
+        assert_eq!(post(addr, "/puzzles/2015/1/1", "1\n").0, 404);
+        assert_eq!(
+            request(addr, "GET /puzzles/2022/1/1 HTTP/1.1\r\n\r\n").0,
+            405
+        );
+        assert_eq!(request(addr, "GET / HTTP/1.1\r\n\r\n").0, 404);
+    }
+
+    #[test]
+    fn limits() {
+        let addr = start(16);
+        assert_eq!(post(addr, "/puzzles/2022/1/1", &"1\n".repeat(8)).0, 200);
+        let (status, body) = post(addr, "/puzzles/2022/1/1", &"1\n".repeat(9));
+        assert_eq!(status, 413);
+        assert_eq!(body, r#"{"error":"request body exceeds 16 bytes"}"#);
+        let (status, _) = request(addr, "POST /puzzles/2022/1/1 HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 411);
+        let header = format!("X-Padding: {}\r\n", "x".repeat(8 * 1024));
+        let (status, _) = request(addr, &format!("GET /puzzles HTTP/1.1\r\n{header}\r\n"));
+        assert_eq!(status, 431);
+    }
+
+    #[test]
+    fn concurrent_requests() {
+        let addr = start(1024);
+        // A client stalling in the middle of a request doesn't block others
+        let mut stalled = TcpStream::connect(addr).unwrap();
+        stalled
+            .write_all(b"POST /puzzles/2022/1/1 HTTP/1.1\r\n")
+            .unwrap();
+        let clients: Vec<_> = (1..=8)
+            .map(|n| thread::spawn(move || post(addr, "/puzzles/2022/1/1", &format!("{n}\n"))))
+            .collect();
+        for (n, client) in (1..=8).zip(clients) {
+            let (status, body) = client.join().unwrap();
+            assert_eq!(status, 200);
+            assert!(body.contains(&format!(r#""value":{n}}}"#)));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Advent of Code: snapshot testing
+//!
+//! Multi-line output like rendered images is hard to read as string literal
+//! in a test. Instead, expected output is stored in a file in `snapshots/`
+//! and compared with the actual output. Running tests with
+//! `AOC_UPDATE_SNAPSHOTS=1` creates or updates snapshot files.
+
+use crate::diff::line_diff;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Path of the snapshot directory
+const SNAPSHOT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots");
+
+/// Environment variable enabling creating and updating snapshots
+const UPDATE_VAR: &str = "AOC_UPDATE_SNAPSHOTS";
+
+/// Result of comparing output with its snapshot
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    /// Output matches the snapshot
+    Matched,
+    /// Snapshot was created or updated with the output
+    Updated,
+    /// There's no snapshot for the output
+    Missing,
+    /// Output differs from the snapshot, with a line diff
+    Changed(String),
+}
+
+/// Path of the snapshot file with the given name
+fn path(name: &str) -> PathBuf {
+    Path::new(SNAPSHOT_PATH).join(format!("{name}.txt"))
+}
+
+/// Compare the given output with the snapshot file, creating or updating
+/// it if requested
+fn compare(path: &Path, actual: &str, update: bool) -> io::Result<Outcome> {
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) if expected == actual => return Ok(Outcome::Matched),
+        Ok(expected) => Some(expected),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+    if update {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, actual)?;
+        return Ok(Outcome::Updated);
+    }
+    Ok(match expected {
+        Some(expected) => Outcome::Changed(line_diff(&expected, actual)),
+        None => Outcome::Missing,
+    })
+}
+
+/// Assert that the given output matches the snapshot with the given name
+/// (`snapshots/<name>.txt`). With `AOC_UPDATE_SNAPSHOTS=1`, the snapshot is
+/// created or updated instead.
+///
+/// # Panics
+/// Panics if the snapshot is missing or differs from the output
+#[track_caller]
+pub(crate) fn assert_matches(name: &str, actual: &str) {
+    let path = path(name);
+    let update = std::env::var(UPDATE_VAR).is_ok_and(|value| value == "1");
+    match compare(&path, actual, update) {
+        Ok(Outcome::Matched | Outcome::Updated) => (),
+        Ok(Outcome::Missing) => panic!(
+            "Snapshot {} doesn't exist, run with {UPDATE_VAR}=1 to create it",
+            path.display()
+        ),
+        Ok(Outcome::Changed(diff)) => panic!(
+            "Output differs from snapshot {} (-expected, +actual):\n{diff}\n\
+            Run with {UPDATE_VAR}=1 to update the snapshot",
+            path.display()
+        ),
+        Err(e) => panic!("Can't read snapshot {}: {e}", path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tempdir::TempDir;
+
+    #[test]
+    fn compare_and_update() {
+        let dir = TempDir::new("snapshot");
+        let path = dir.join("nested/output.txt");
+        assert_eq!(compare(&path, "#.\n.#\n", false).unwrap(), Outcome::Missing);
+        assert_eq!(compare(&path, "#.\n.#\n", true).unwrap(), Outcome::Updated);
+        assert_eq!(compare(&path, "#.\n.#\n", false).unwrap(), Outcome::Matched);
+        assert_eq!(
+            compare(&path, "#.\n##\n", false).unwrap(),
+            Outcome::Changed(" #.\n-.#\n+##".to_string())
+        );
+        assert_eq!(compare(&path, "#.\n##\n", true).unwrap(), Outcome::Updated);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "#.\n##\n");
+    }
+}
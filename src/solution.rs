@@ -0,0 +1,71 @@
+//! Advent of Code: puzzle solutions
+
+use crate::visualize::Render;
+use crate::{Answer, Input, Result};
+use rand::RngCore;
+use std::io::Read;
+
+/// Puzzle solution
+///
+/// A solution is created by parsing the puzzle input and then answers both
+/// parts of the puzzle.
+pub trait Solution: Sized {
+    /// Answer to part 1
+    type Answer1: Into<Answer>;
+    /// Answer to part 2
+    type Answer2: Into<Answer>;
+
+    /// Parse puzzle input
+    ///
+    /// # Errors
+    /// I/O or parse error
+    fn parse<R: Read>(input: Input<R>) -> Result<Self>;
+
+    /// Answer part 1
+    ///
+    /// # Errors
+    /// Invalid puzzle state or unsolvable puzzle
+    fn part_1(&self) -> Result<Self::Answer1>;
+
+    /// Answer part 2
+    ///
+    /// # Errors
+    /// Invalid puzzle state or unsolvable puzzle
+    fn part_2(&self) -> Result<Self::Answer2>;
+
+    /// Render the steps of solving the puzzle, if the solution supports
+    /// visualization
+    fn visualize(&self, _render: &mut dyn Render) -> Option<Result<()>> {
+        None
+    }
+
+    /// Generate a random valid puzzle input of roughly the given size (e.g.
+    /// number of lines), if the solution supports generating inputs
+    fn generate(_rng: &mut dyn RngCore, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+/// Parsed puzzle with type-erased answers
+pub trait Solver {
+    /// Answer the given part (1 or 2) of the puzzle, if it exists
+    fn solve(&self, part: u8) -> Option<Result<Answer>>;
+
+    /// Render the steps of solving the puzzle, if the solution supports
+    /// visualization
+    fn visualize(&self, render: &mut dyn Render) -> Option<Result<()>>;
+}
+
+impl<S: Solution> Solver for S {
+    fn solve(&self, part: u8) -> Option<Result<Answer>> {
+        match part {
+            1 => Some(self.part_1().map(Into::into)),
+            2 => Some(self.part_2().map(Into::into)),
+            _ => None,
+        }
+    }
+
+    fn visualize(&self, render: &mut dyn Render) -> Option<Result<()>> {
+        Solution::visualize(self, render)
+    }
+}
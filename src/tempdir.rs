@@ -0,0 +1,66 @@
+//! Advent of Code: temporary directories for tests
+//!
+//! Each [`TempDir`] gets a unique path, so that tests running in parallel
+//! (or in several test processes) don't interfere, and is removed when it's
+//! dropped, even if the test panicked.
+
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of temporary directories created by this process
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Temporary directory, removed with its contents when dropped
+#[derive(Debug)]
+pub(crate) struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Create a new empty temporary directory, whose name starts with the
+    /// given prefix
+    ///
+    /// # Panics
+    /// If the directory can't be created
+    pub(crate) fn new(prefix: &str) -> Self {
+        let n = COUNT.fetch_add(1, Ordering::Relaxed);
+        let name = format!("aoc-{prefix}-{}-{n}", process::id());
+        let path = std::env::temp_dir().join(name);
+        // Left over from an earlier process with the same id
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_and_removed() {
+        let a = TempDir::new("tempdir");
+        let b = TempDir::new("tempdir");
+        assert_ne!(a.to_path_buf(), b.to_path_buf());
+        fs::write(a.join("file.txt"), "x").unwrap();
+        let path = a.to_path_buf();
+        drop(a);
+        assert!(!path.exists());
+        assert!(b.is_dir());
+    }
+}
@@ -0,0 +1,272 @@
+//! Advent of Code: visualization of puzzle simulations
+//!
+//! Solutions that simulate something emit a [`Frame`] per step to a
+//! [`Render`] backend, which either animates frames in the terminal
+//! ([`Terminal`]) or exports them to a sequence of images ([`Svg`]).
+
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+/// Single frame of a visualization: a grid of characters. Spaces are blank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<char>,
+}
+
+impl Frame {
+    /// Create blank frame of the given size
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![' '; width * height],
+        }
+    }
+
+    /// Width of this frame
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of this frame
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Character at the given position
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// Set character at the given position. Positions outside of this
+    /// frame are ignored.
+    pub fn set(&mut self, x: usize, y: usize, ch: char) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = ch;
+        }
+    }
+
+    /// Write text starting at the given position
+    pub fn text(&mut self, x: usize, y: usize, text: &str) {
+        for (i, ch) in text.chars().enumerate() {
+            self.set(x + i, y, ch);
+        }
+    }
+
+    /// Iterator over rows of characters
+    fn rows(&self) -> impl Iterator<Item = &[char]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+}
+
+impl From<&str> for Frame {
+    fn from(s: &str) -> Self {
+        let width = s
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut frame = Self::new(width, s.lines().count());
+        for (y, line) in s.lines().enumerate() {
+            frame.text(0, y, line);
+        }
+        frame
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>().trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Visualization backend
+pub trait Render {
+    /// Render the next frame
+    ///
+    /// # Errors
+    /// I/O error
+    fn render(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+/// Collecting frames in memory
+impl Render for Vec<Frame> {
+    fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        self.push(frame.clone());
+        Ok(())
+    }
+}
+
+/// Animation of frames in the terminal
+#[derive(Debug)]
+pub struct Terminal<W: Write> {
+    out: W,
+    delay: Duration,
+    frames: usize,
+}
+
+impl<W: Write> Terminal<W> {
+    /// Create terminal animation writing to the given output, showing each
+    /// frame for the given time
+    pub fn new(out: W, delay: Duration) -> Self {
+        Self {
+            out,
+            delay,
+            frames: 0,
+        }
+    }
+}
+
+impl<W: Write> Render for Terminal<W> {
+    fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        if self.frames == 0 {
+            // Clear screen
+            write!(self.out, "\x1b[2J")?;
+        }
+        // Move cursor to top left, clear rest of each line and everything
+        // below the frame
+        write!(self.out, "\x1b[H")?;
+        for line in frame.to_string().lines() {
+            writeln!(self.out, "{line}\x1b[K")?;
+        }
+        write!(self.out, "\x1b[J")?;
+        self.out.flush()?;
+        self.frames += 1;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+/// Size of a cell in exported images, in pixels
+const CELL_SIZE: usize = 10;
+
+/// Background color of exported images
+const BACKGROUND: &str = "#0f0f23";
+
+/// Colors of characters in exported images
+const PALETTE: &[&str] = &[
+    "#ffff66", "#009900", "#cccccc", "#e74c3c", "#3498db", "#9b59b6", "#e67e22", "#1abc9c",
+];
+
+/// Export of frames to a sequence of SVG images (`frame00001.svg`, ...)
+#[derive(Debug)]
+pub struct Svg {
+    dir: PathBuf,
+    frames: usize,
+}
+
+impl Svg {
+    /// Create export to the given directory
+    ///
+    /// # Errors
+    /// I/O error
+    pub fn new<P: Into<PathBuf>>(dir: P) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir, frames: 0 })
+    }
+
+    /// Number of exported frames
+    #[must_use]
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+}
+
+impl Render for Svg {
+    fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        self.frames += 1;
+        let filename = self.dir.join(format!("frame{:05}.svg", self.frames));
+        fs::write(filename, svg(frame))
+    }
+}
+
+/// Draw frame as SVG image. Non-blank characters are drawn as colored
+/// cells, letters and digits are labeled.
+fn svg(frame: &Frame) -> String {
+    let (width, height) = (frame.width * CELL_SIZE, frame.height * CELL_SIZE);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\">\n\
+        <rect width=\"100%\" height=\"100%\" fill=\"{BACKGROUND}\"/>\n"
+    );
+    for (y, row) in frame.rows().enumerate() {
+        for (x, &ch) in row.iter().enumerate().filter(|(_, ch)| **ch != ' ') {
+            let (x, y) = (x * CELL_SIZE, y * CELL_SIZE);
+            let color = PALETTE[ch as usize % PALETTE.len()];
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{CELL_SIZE}\" height=\"{CELL_SIZE}\" fill=\"{color}\"/>"
+            );
+            if ch.is_alphanumeric() {
+                let (x, y) = (x + CELL_SIZE / 2, y + CELL_SIZE / 2);
+                let _ = writeln!(
+                    svg,
+                    "<text x=\"{x}\" y=\"{y}\" font-family=\"monospace\" font-size=\"{CELL_SIZE}\" \
+                    text-anchor=\"middle\" dominant-baseline=\"central\">{ch}</text>"
+                );
+            }
+        }
+    }
+    svg + "</svg>\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tempdir::TempDir;
+
+    #[test]
+    fn frame() {
+        let mut frame = Frame::new(4, 2);
+        frame.set(0, 0, '#');
+        frame.text(1, 1, "abcd");
+        frame.set(9, 9, '#');
+        assert_eq!(frame.get(0, 0), Some('#'));
+        assert_eq!(frame.get(3, 1), Some('c'));
+        assert_eq!(frame.get(4, 1), None);
+        assert_eq!(frame.to_string(), "#\n abc\n");
+        assert_eq!(Frame::from("#\n abc\n"), frame);
+    }
+
+    #[test]
+    fn terminal() {
+        let mut terminal = Terminal::new(Vec::new(), Duration::ZERO);
+        terminal.render(&Frame::from("#")).unwrap();
+        terminal.render(&Frame::from(" #")).unwrap();
+        let output = String::from_utf8(terminal.out).unwrap();
+        assert_eq!(output, "\x1b[2J\x1b[H#\x1b[K\n\x1b[J\x1b[H #\x1b[K\n\x1b[J");
+    }
+
+    #[test]
+    fn svg_export() {
+        let dir = TempDir::new("svg");
+        let mut svg = Svg::new(&*dir).unwrap();
+        svg.render(&Frame::from("#")).unwrap();
+        svg.render(&Frame::from("##")).unwrap();
+        assert_eq!(svg.frames(), 2);
+        assert!(dir.join("frame00002.svg").exists());
+    }
+
+    #[test]
+    fn svg_image() {
+        let svg = svg(&Frame::from("#A"));
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"20\""));
+        assert_eq!(svg.matches("<rect ").count(), 3);
+        assert_eq!(svg.matches("<text ").count(), 1);
+        assert!(svg.contains(">A</text>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,104 @@
+//! Advent of Code: WebAssembly entry points
+//!
+//! Exposes puzzle solutions to JavaScript via wasm-bindgen, e.g. for a
+//! browser playground. Inputs are passed as strings, answers and errors are
+//! returned as strings. On other targets, these are plain functions.
+//!
+//! Build with `cargo build --lib --target wasm32-unknown-unknown`.
+
+use crate::error::describe;
+use crate::registry::{self, SolveError};
+use crate::Result;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Days of the given year with puzzle solutions
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[must_use]
+pub fn days(year: u16) -> Vec<usize> {
+    registry::year(year).map(|puzzle| puzzle.day).collect()
+}
+
+/// Answer the given part of a puzzle for the given input
+///
+/// # Errors
+/// Unknown puzzle or part, parse error or failure to answer the part
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn solve(year: u16, day: usize, part: u8, input: &str) -> Result<String, String> {
+    match registry::solve(year, day, part, input) {
+        Ok(answer) => Ok(answer.to_string()),
+        Err(SolveError::Parse(e) | SolveError::Solve(e)) => Err(describe(&e)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Define an entry point for each given day, answering a part of its puzzle
+macro_rules! entry_points {
+    ($year:literal: $($name:ident => $day:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Answer the given part of ", $year, " day ", $day, " for the given input")]
+            ///
+            /// # Errors
+            /// Unknown part, parse error or failure to answer the part
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+            pub fn $name(part: u8, input: &str) -> Result<String, String> {
+                solve($year, $day, part, input)
+            }
+        )*
+
+        /// Puzzles with entry points
+        #[cfg(test)]
+        const ENTRY_POINTS: &[(u16, usize)] = &[$(($year, $day)),*];
+    };
+}
+
+entry_points!(2022:
+    day01 => 1,
+    day02 => 2,
+    day03 => 3,
+    day04 => 4,
+    day05 => 5,
+    day06 => 6,
+    day07 => 7,
+    day08 => 8,
+    day09 => 9,
+    day10 => 10,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test as test;
+
+    #[test]
+    fn entry_points() {
+        let puzzles: Vec<_> = registry::puzzles()
+            .map(|puzzle| (puzzle.year, puzzle.day))
+            .collect();
+        assert_eq!(puzzles, ENTRY_POINTS, "puzzles without entry point");
+        assert_eq!(days(2022), (1..=10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn solve_input() {
+        assert_eq!(day01(1, "1\n2\n\n4\n").unwrap(), "4");
+        assert_eq!(solve(2022, 1, 2, "1\n2\n\n4\n").unwrap(), "7");
+        assert_eq!(day01(3, "1\n").unwrap_err(), "no part 3 in 2022 day 1");
+        assert_eq!(solve(2015, 1, 1, "").unwrap_err(), "no puzzle 2015 day 1");
+        assert_eq!(
+            day01(1, "x\n").unwrap_err(),
+            "Parse error at line 1: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn solve_generated_inputs() {
+        for puzzle in registry::puzzles() {
+            let input = puzzle.generate(1, 30).unwrap();
+            for part in 1..=2 {
+                assert!(solve(puzzle.year, puzzle.day, part, &input).is_ok());
+            }
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Advent of Code: watch mode
+//!
+//! Polls the input, example inputs and solution source of a puzzle for
+//! modifications, so that the puzzle can be re-run whenever they change.
+
+use crate::diff::line_diff;
+use crate::input::input_files;
+use crate::runner::Run;
+use crate::{Input, Puzzle, Result};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Source root of this crate
+const ROOT_PATH: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Interval of polling for modifications
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification times of existing files
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+    fn take(paths: &[PathBuf]) -> Self {
+        Self(
+            paths
+                .iter()
+                .filter_map(|path| {
+                    let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+                    Some((path.clone(), modified))
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Kind of modification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// Puzzle input or example inputs changed
+    Input,
+    /// Solution source changed
+    Source,
+}
+
+/// Watcher of a puzzle's inputs and source
+#[derive(Debug)]
+pub struct Watcher {
+    puzzle: Puzzle,
+    inputs: Snapshot,
+    source: Snapshot,
+    answers: BTreeMap<String, Vec<String>>,
+}
+
+impl Watcher {
+    /// Create watcher for the given puzzle
+    #[must_use]
+    pub fn new(puzzle: Puzzle) -> Self {
+        let mut watcher = Self {
+            puzzle,
+            inputs: Snapshot::default(),
+            source: Snapshot::default(),
+            answers: BTreeMap::new(),
+        };
+        watcher.source = Snapshot::take(&[watcher.source_path()]);
+        watcher.inputs = Snapshot::take(&watcher.input_paths());
+        watcher
+    }
+
+    /// Path of the solution source
+    fn source_path(&self) -> PathBuf {
+        Path::new(ROOT_PATH).join(format!(
+            "src/year{}/day{:02}.rs",
+            self.puzzle.year, self.puzzle.day
+        ))
+    }
+
+    /// Paths of the puzzle input and example inputs
+    fn input_paths(&self) -> Vec<PathBuf> {
+        let mut paths = input_files(self.puzzle.year, self.puzzle.day);
+        paths.extend(self.puzzle.examples().unwrap_or_default());
+        paths
+    }
+
+    /// Wait until the puzzle input, example inputs or solution source change
+    pub fn wait(&mut self) -> Change {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let source = Snapshot::take(&[self.source_path()]);
+            if source != self.source {
+                self.source = source;
+                return Change::Source;
+            }
+            let inputs = Snapshot::take(&self.input_paths());
+            if inputs != self.inputs {
+                self.inputs = inputs;
+                return Change::Input;
+            }
+        }
+    }
+
+    /// Run the puzzle with its input and all example inputs. Returns a
+    /// report of answers and timings, including changes since the previous
+    /// run.
+    pub fn run(&mut self) -> String {
+        let mut report = format!("{} day {}:\n", self.puzzle.year, self.puzzle.day);
+        let mut inputs = Vec::new();
+        match self.puzzle.input() {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+            res => {
+                if let Some(mismatch) = res.as_ref().ok().and_then(Input::checksum_mismatch) {
+                    let _ = writeln!(report, "Warning: {mismatch}");
+                }
+                inputs.push(("input".to_string(), res.and_then(Input::into_string)));
+            }
+        }
+        for path in self.puzzle.examples().unwrap_or_default() {
+            let name = path.strip_prefix(ROOT_PATH).unwrap_or(&path);
+            inputs.push((name.display().to_string(), fs::read_to_string(&path)));
+        }
+        for (name, input) in inputs {
+            let run = input
+                .map_err(Into::into)
+                .and_then(|input| Run::new(&self.puzzle, &input));
+            report += &self.report(name, run);
+        }
+        report
+    }
+
+    /// Report a run with the given input, remembering its answers
+    fn report(&mut self, name: String, run: Result<Run>) -> String {
+        let run = match run {
+            Ok(run) => run,
+            Err(e) => return format!("{name}: Error: {e}\n"),
+        };
+        let mut report = format!("{name}: parsed in {:.2?}\n", run.parse_duration);
+        let answers = run
+            .parts
+            .iter()
+            .map(|part| match &part.answer {
+                Ok(answer) => answer.to_string(),
+                Err(e) => format!("Error: {e}"),
+            })
+            .collect_vec();
+        let previous = self.answers.insert(name, answers.clone());
+        for (part, answer) in run.parts.iter().zip(&answers) {
+            let (n, duration) = (part.part, part.duration);
+            let diff = previous
+                .as_ref()
+                .and_then(|previous| previous.get(usize::from(n) - 1))
+                .and_then(|previous| diff(previous, answer));
+            let _ = match diff {
+                Some(diff) if answer.contains('\n') => {
+                    writeln!(report, "  Part {n} ({duration:.2?}), changed:\n{diff}")
+                }
+                Some(diff) => writeln!(report, "  Part {n}: {answer} ({duration:.2?}, {diff})"),
+                None if answer.contains('\n') => {
+                    writeln!(report, "  Part {n} ({duration:.2?}):\n{answer}")
+                }
+                None => writeln!(report, "  Part {n}: {answer} ({duration:.2?})"),
+            };
+        }
+        report
+    }
+}
+
+/// Describe how an answer differs from the previous answer. Multi-line
+/// answers are compared line by line.
+fn diff(previous: &str, answer: &str) -> Option<String> {
+    if previous == answer {
+        None
+    } else if !previous.contains('\n') && !answer.contains('\n') {
+        Some(format!("was {previous}"))
+    } else {
+        Some(line_diff(previous, answer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn diff_answers() {
+        assert_eq!(diff("42", "42"), None);
+        assert_eq!(diff("41", "42").unwrap(), "was 41");
+        assert_eq!(diff("#.\n.#", "#.\n##\n..").unwrap(), " #.\n-.#\n+##\n+..");
+    }
+
+    #[test]
+    fn report_changes() {
+        let mut watcher = Watcher::new(*registry::find(2022, 1).unwrap());
+        let puzzle = watcher.puzzle;
+        let run = |input| Run::new(&puzzle, input);
+        let (first, second) = (run("1\n\n2\n"), run("1\n\n3\n"));
+        let report = watcher.report("example".to_string(), first);
+        assert!(report.starts_with("example: parsed in "));
+        assert!(report.contains("  Part 1: 2 ("));
+        let report = watcher.report("example".to_string(), second);
+        assert!(report.contains("  Part 1: 3 (") && report.contains(", was 2)\n"));
+        assert!(report.contains("  Part 2: 4 (") && report.contains(", was 3)\n"));
+        let report = watcher.report("example".to_string(), run("x\n"));
+        assert!(report.starts_with("example: Error: Parse error"));
+    }
+
+    #[test]
+    fn snapshot() {
+        let path = PathBuf::from(ROOT_PATH).join("Cargo.toml");
+        let snapshot = Snapshot::take(&[path.clone(), path.with_extension("missing")]);
+        assert_eq!(snapshot.0.len(), 1);
+        assert_eq!(snapshot, Snapshot::take(&[path]));
+    }
+}
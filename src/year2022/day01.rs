@@ -0,0 +1,78 @@
+//! Day 1: Calorie Counting
+
+use crate::{Input, Result, Solution};
+use itertools::Itertools;
+use rand::{Rng, RngCore};
+use std::fmt::Write;
+use std::io::Read;
+
+fn parse<R: Read>(input: Input<R>) -> Result<Vec<Vec<u32>>> {
+    input.blocks_parse().try_collect()
+}
+
+fn max_calories<C: AsRef<[u32]>>(calories: &[C]) -> u32 {
+    calories
+        .iter()
+        .map(|c| c.as_ref().iter().sum())
+        .max()
+        .unwrap_or(0)
+}
+
+fn top_calories<C: AsRef<[u32]>>(calories: &[C], n: usize) -> u32 {
+    let mut calories_totals: Vec<u32> = calories.iter().map(|c| c.as_ref().iter().sum()).collect();
+    calories_totals.sort_unstable();
+    calories_totals.iter().rev().take(n).sum()
+}
+
+/// Calories carried by each elf
+#[derive(Debug)]
+pub struct Calories(Vec<Vec<u32>>);
+
+impl Solution for Calories {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        Ok(Self(parse(input)?))
+    }
+
+    fn part_1(&self) -> Result<u32> {
+        Ok(max_calories(&self.0))
+    }
+
+    fn part_2(&self) -> Result<u32> {
+        Ok(top_calories(&self.0, 3))
+    }
+
+    /// Inventories of the given number of elves
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let elf = |rng: &mut dyn RngCore| {
+            let items = rng.gen_range(1..=15);
+            (0..items).fold(String::new(), |mut s, _| {
+                let _ = writeln!(s, "{}", rng.gen_range(1000..=60000));
+                s
+            })
+        };
+        Some((0..size.max(3)).map(|_| elf(rng)).join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calories() -> Vec<Vec<u32>> {
+        const INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+        parse(Input::from(INPUT)).unwrap()
+    }
+
+    #[test]
+    fn part_1() {
+        assert_eq!(max_calories(&calories()), 24000);
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(top_calories(&calories(), 3), 45000);
+    }
+}
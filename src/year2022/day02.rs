@@ -0,0 +1,195 @@
+//! Day 2: Rock Paper Scissors
+
+use crate::{Error, Input, Result, Solution};
+use rand::{Rng, RngCore};
+use std::{cmp::Ordering, io::Read, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl FromStr for Hand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "A" => Ok(Self::Rock),
+            "B" => Ok(Self::Paper),
+            "C" => Ok(Self::Scissors),
+            _ => Err(Error::parse(format!("invalid hand `{s}`"))),
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(match (self, other) {
+            _ if self == other => Ordering::Equal,
+            (Self::Rock, Self::Scissors)
+            | (Self::Paper, Self::Rock)
+            | (Self::Scissors, Self::Paper) => Ordering::Greater,
+            _ => Ordering::Less,
+        })
+    }
+}
+
+impl Hand {
+    fn score(self) -> u32 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    fn score_against(self, opponent: Hand) -> u32 {
+        self.score()
+            + if self > opponent {
+                6
+            } else if self == opponent {
+                3
+            } else {
+                0
+            }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Strategy {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl FromStr for Strategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "X" => Ok(Self::Lose),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            _ => Err(Error::parse(format!("invalid strategy `{s}`"))),
+        }
+    }
+}
+
+impl Strategy {
+    // Part 1 stupid strategy: select X->Rock, Y->Paper, Z->Scissors
+    fn select_stupid(self) -> Hand {
+        match self {
+            Self::Lose => Hand::Rock,
+            Self::Draw => Hand::Paper,
+            Self::Win => Hand::Scissors,
+        }
+    }
+
+    // Part 2 smart strategy: select for desired outcome
+    fn select_smart(self, opponent: Hand) -> Hand {
+        match (self, opponent) {
+            (Self::Lose, Hand::Paper) | (Self::Win, Hand::Scissors) => Hand::Rock,
+            (Self::Lose, Hand::Scissors) | (Self::Win, Hand::Rock) => Hand::Paper,
+            (Self::Lose, Hand::Rock) | (Self::Win, Hand::Paper) => Hand::Scissors,
+            (Self::Draw, _) => opponent,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Round {
+    opponent: Hand,
+    strategy: Strategy,
+}
+
+impl FromStr for Round {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split(char::is_whitespace);
+        let mut part = || parts.next().ok_or_else(|| Error::parse("missing column"));
+        Ok(Self {
+            opponent: part()?.parse()?,
+            strategy: part()?.parse()?,
+        })
+    }
+}
+
+impl Round {
+    fn score_stupid(&self) -> u32 {
+        self.strategy.select_stupid().score_against(self.opponent)
+    }
+
+    fn score_smart(&self) -> u32 {
+        self.strategy
+            .select_smart(self.opponent)
+            .score_against(self.opponent)
+    }
+}
+
+/// Encrypted strategy guide
+#[derive(Debug)]
+pub struct StrategyGuide(Vec<Round>);
+
+impl Solution for StrategyGuide {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        Ok(Self(input.lines_parse_all()?))
+    }
+
+    fn part_1(&self) -> Result<u32> {
+        Ok(self.0.iter().map(Round::score_stupid).sum())
+    }
+
+    fn part_2(&self) -> Result<u32> {
+        Ok(self.0.iter().map(Round::score_smart).sum())
+    }
+
+    /// Strategy guide with the given number of rounds
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let round = |rng: &mut dyn RngCore| {
+            let opponent = ['A', 'B', 'C'][rng.gen_range(0..3)];
+            let strategy = ['X', 'Y', 'Z'][rng.gen_range(0..3)];
+            format!("{opponent} {strategy}\n")
+        };
+        Some((0..size).map(|_| round(rng)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    fn rounds() -> Vec<Round> {
+        const INPUT: &str = "A Y\nB X\nC Z\n";
+        Input::from(INPUT).lines_parse().try_collect().unwrap()
+    }
+
+    #[test]
+    fn part_1() {
+        let scores: Vec<_> = rounds().iter().map(Round::score_stupid).collect();
+        assert_eq!(scores, [8, 1, 6]);
+    }
+
+    #[test]
+    fn part_2() {
+        let scores: Vec<_> = rounds().iter().map(Round::score_smart).collect();
+        assert_eq!(scores, [4, 1, 7]);
+    }
+
+    #[test]
+    fn all_parse_errors() {
+        let err = StrategyGuide::parse(Input::from("A Y\nD X\nC Z\nB\n")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2 errors:\n  \
+            Parse error at line 2: invalid hand `D`\n  \
+            Parse error at line 4: missing column"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! Day 3: Rucksack Reorganization
+
+use crate::bitset::CharSet;
+use crate::{Input, Result, Solution};
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng, RngCore};
+use std::io::Read;
+
+fn item_priority(item: char) -> u32 {
+    match item {
+        'a'..='z' => item as u32 - 'a' as u32 + 1,
+        'A'..='Z' => item as u32 - 'A' as u32 + 27,
+        _ => 0,
+    }
+}
+
+#[derive(Debug)]
+struct Rucksack {
+    items: String,
+}
+
+impl From<String> for Rucksack {
+    fn from(items: String) -> Self {
+        Self { items }
+    }
+}
+
+impl Rucksack {
+    fn compartments(&self) -> (&str, &str) {
+        self.items.split_at(self.items.len() / 2)
+    }
+
+    fn common_item(&self) -> Option<char> {
+        let (a, b) = self.compartments();
+        (CharSet::from(a) & CharSet::from(b)).iter().next()
+    }
+
+    fn common_item_priority(&self) -> u32 {
+        self.common_item().map_or(0, item_priority)
+    }
+}
+
+fn find_badge_item(rucksacks: &[Rucksack]) -> Option<char> {
+    let items = rucksacks.iter().map(|r| CharSet::from(r.items.as_str()));
+    items.reduce(|a, b| a & b)?.iter().next()
+}
+
+fn find_badge_item_priority(rucksacks: &[Rucksack]) -> u32 {
+    find_badge_item(rucksacks).map_or(0, item_priority)
+}
+
+/// Rucksacks of all elves
+#[derive(Debug)]
+pub struct Rucksacks(Vec<Rucksack>);
+
+impl Solution for Rucksacks {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        Ok(Self(input.lines_into().try_collect()?))
+    }
+
+    fn part_1(&self) -> Result<u32> {
+        Ok(self.0.iter().map(Rucksack::common_item_priority).sum())
+    }
+
+    fn part_2(&self) -> Result<u32> {
+        Ok(self.0.chunks(3).map(find_badge_item_priority).sum())
+    }
+
+    /// Rucksacks of groups of three elves, about the given number of lines
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        let mut rucksacks = String::new();
+        for _ in 0..(size / 3).max(1) {
+            // All items except the badge are left out of at least one
+            // rucksack of the group
+            let badge = *items.choose(rng)?;
+            let left_out: Vec<usize> = items.iter().map(|_| rng.gen_range(0..3)).collect();
+            for elf in 0..3 {
+                let mut available: Vec<char> = items
+                    .iter()
+                    .zip(&left_out)
+                    .filter(|&(&item, &i)| item != badge && i != elf)
+                    .map(|(&item, _)| item)
+                    .collect();
+                available.shuffle(rng);
+                // Compartments have one item in common, the badge is only
+                // in the first one
+                let common = available.pop()?;
+                let (first, second) = available.split_at(available.len() / 2);
+                let len = rng.gen_range(2..=16);
+                let mut compartment = |available: &[char], items: &[char]| {
+                    let mut items = items.to_vec();
+                    while items.len() < len {
+                        items.push(*available.choose(rng)?);
+                    }
+                    items.shuffle(rng);
+                    Some(items.into_iter().collect::<String>())
+                };
+                rucksacks += &compartment(first, &[common, badge])?;
+                rucksacks += &compartment(second, &[common])?;
+                rucksacks.push('\n');
+            }
+        }
+        Some(rucksacks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn rucksacks() -> Vec<Rucksack> {
+        const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw\n";
+        Input::from(INPUT).lines_into().try_collect().unwrap()
+    }
+
+    #[test]
+    fn item_priorities() {
+        assert_eq!(item_priority('p'), 16);
+        assert_eq!(item_priority('L'), 38);
+        assert_eq!(item_priority('P'), 42);
+        assert_eq!(item_priority('v'), 22);
+        assert_eq!(item_priority('t'), 20);
+        assert_eq!(item_priority('s'), 19);
+    }
+
+    #[test]
+    fn part_1() {
+        let priorities: Vec<_> = rucksacks()
+            .iter()
+            .map(Rucksack::common_item_priority)
+            .collect();
+        assert_eq!(priorities, [16, 38, 42, 22, 20, 19]);
+    }
+
+    #[test]
+    fn part_2() {
+        let priorities: Vec<_> = rucksacks()
+            .chunks(3)
+            .map(find_badge_item_priority)
+            .collect();
+        assert_eq!(priorities, [18, 52]);
+    }
+
+    #[test]
+    fn generated_rucksacks() {
+        let input = Rucksacks::generate(&mut StdRng::seed_from_u64(3), 300).unwrap();
+        let rucksacks = Rucksacks::parse(Input::from(input.as_str())).unwrap().0;
+        assert_eq!(rucksacks.len(), 300);
+        for rucksack in &rucksacks {
+            let (a, b) = rucksack.compartments();
+            assert_eq!((CharSet::from(a) & CharSet::from(b)).len(), 1);
+        }
+        for group in rucksacks.chunks(3) {
+            let items = group.iter().map(|r| CharSet::from(r.items.as_str()));
+            assert_eq!(items.reduce(|a, b| a & b).unwrap().len(), 1);
+        }
+    }
+}
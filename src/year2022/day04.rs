@@ -0,0 +1,115 @@
+//! Day 4: Camp Cleanup
+
+use crate::interval::IntervalSet;
+use crate::{Error, Input, Result, Solution};
+use rand::{Rng, RngCore};
+use std::{fmt::Write, io::Read, ops::RangeInclusive, str::FromStr};
+
+fn parse_range(s: &str) -> Result<RangeInclusive<u32>> {
+    let mut numbers = s.split('-');
+    match (numbers.next(), numbers.next()) {
+        (Some(start), Some(end)) => {
+            let (start, end): (u32, u32) = (start.parse()?, end.parse()?);
+            if start > end {
+                return Err(Error::parse(format!("reversed range `{s}`")));
+            }
+            Ok(start..=end)
+        }
+        _ => Err(Error::parse(format!("invalid range `{s}`"))),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Pair(RangeInclusive<u32>, RangeInclusive<u32>);
+
+impl FromStr for Pair {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut ranges = s.split(',');
+        match (ranges.next(), ranges.next()) {
+            (Some(first), Some(second)) => Ok(Self(parse_range(first)?, parse_range(second)?)),
+            _ => Err(Error::parse(format!("invalid pair `{s}`"))),
+        }
+    }
+}
+
+impl Pair {
+    fn sections(&self) -> (IntervalSet<u32>, IntervalSet<u32>) {
+        (self.0.clone().into(), self.1.clone().into())
+    }
+
+    fn fully_contained(&self) -> bool {
+        let (sections1, sections2) = self.sections();
+        sections1.is_superset(&sections2) || sections2.is_superset(&sections1)
+    }
+
+    fn overlap(&self) -> bool {
+        let (sections1, sections2) = self.sections();
+        !sections1.intersection(&sections2).is_empty()
+    }
+}
+
+/// Section assignments of all pairs of elves
+#[derive(Debug)]
+pub struct Assignments(Vec<Pair>);
+
+impl Solution for Assignments {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        Ok(Self(input.lines_parse_all()?))
+    }
+
+    fn part_1(&self) -> Result<usize> {
+        Ok(self.0.iter().filter(|p| p.fully_contained()).count())
+    }
+
+    fn part_2(&self) -> Result<usize> {
+        Ok(self.0.iter().filter(|p| p.overlap()).count())
+    }
+
+    /// Section assignments of the given number of pairs
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let range = |rng: &mut dyn RngCore| {
+            let start = rng.gen_range(1..=99);
+            format!("{start}-{}", rng.gen_range(start..=99))
+        };
+        Some((0..size).fold(String::new(), |mut s, _| {
+            let _ = writeln!(s, "{},{}", range(rng), range(rng));
+            s
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    fn pairs() -> Vec<Pair> {
+        const INPUT: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n";
+        Input::from(INPUT).lines_parse().try_collect().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("2-3,6-7".parse::<Pair>().unwrap(), Pair(2..=3, 6..=7));
+        assert!("5-3,1-2".parse::<Pair>().is_err());
+    }
+
+    #[test]
+    fn part_1() {
+        let pairs = pairs();
+        let contained: Vec<_> = pairs.iter().filter(|p| p.fully_contained()).collect();
+        assert_eq!(contained, [&pairs[3], &pairs[4]]);
+    }
+
+    #[test]
+    fn part_2() {
+        let pairs = pairs();
+        let overlap: Vec<_> = pairs.iter().filter(|p| p.overlap()).collect();
+        assert_eq!(overlap, [&pairs[2], &pairs[3], &pairs[4], &pairs[5]]);
+    }
+}
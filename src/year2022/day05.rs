@@ -0,0 +1,305 @@
+//! Day 5: Supply Stacks
+
+use crate::visualize::{Frame, Render};
+use crate::{Error, Input, Result, Solution};
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng, RngCore};
+use std::{fmt::Write, io::Read, slice, str::FromStr};
+
+#[derive(Debug, Clone)]
+struct Supply(Vec<Vec<char>>);
+
+impl TryFrom<Vec<String>> for Supply {
+    type Error = Error;
+
+    fn try_from(drawing: Vec<String>) -> Result<Self> {
+        let mut stacks = Vec::new();
+        for line in drawing.into_iter().rev().skip(1) {
+            for (i, item) in line.chars().chunks(4).into_iter().enumerate() {
+                while stacks.len() < i + 1 {
+                    stacks.push(Vec::new());
+                }
+                if let Some(('[', ch, ']')) = item.take(3).collect_tuple() {
+                    stacks[i].push(ch);
+                }
+            }
+        }
+        Ok(Self(stacks))
+    }
+}
+
+impl Supply {
+    fn top_items(&self) -> Vec<char> {
+        self.0
+            .iter()
+            .filter_map(|stack| stack.last().copied())
+            .collect()
+    }
+
+    fn frame(&self, height: usize) -> Frame {
+        let mut frame = Frame::new(self.0.len() * 2, height + 1);
+        for (i, stack) in self.0.iter().enumerate() {
+            for (j, item) in stack.iter().enumerate() {
+                frame.set(i * 2, height - 1 - j, *item);
+            }
+            frame.text(i * 2, height, &((i + 1) % 10).to_string());
+        }
+        frame
+    }
+
+    fn stack(&mut self, n: usize) -> Result<&mut Vec<char>> {
+        n.checked_sub(1)
+            .and_then(|i| self.0.get_mut(i))
+            .ok_or_else(|| Error::invalid_state(format!("no stack {n}")))
+    }
+
+    fn apply_steps_single(&mut self, steps: &[Step]) -> Result<()> {
+        for step in steps {
+            for _ in 0..step.count {
+                let item = self
+                    .stack(step.from)?
+                    .pop()
+                    .ok_or_else(|| Error::invalid_state(format!("stack {} empty", step.from)))?;
+                self.stack(step.to)?.push(item);
+            }
+        }
+        Ok(())
+    }
+
+    /// Move the crates of each step as one block, reversed. Gives the same
+    /// result as [`Self::apply_steps_single`]. Only used in tests, as an
+    /// alternative implementation of part 1 to check against.
+    #[cfg(test)]
+    fn apply_steps_batched(&mut self, steps: &[Step]) -> Result<()> {
+        self.apply_steps_with(steps, |items| items.reverse())
+    }
+
+    fn apply_steps_multi(&mut self, steps: &[Step]) -> Result<()> {
+        self.apply_steps_with(steps, |_| ())
+    }
+
+    /// Move all crates of each step at once, arranging moved crates with
+    /// the given function
+    fn apply_steps_with<F: Fn(&mut Vec<char>)>(
+        &mut self,
+        steps: &[Step],
+        arrange: F,
+    ) -> Result<()> {
+        for step in steps {
+            let from_stack = self.stack(step.from)?;
+            let at = from_stack.len().checked_sub(step.count).ok_or_else(|| {
+                Error::invalid_state(format!(
+                    "stack {} has less than {} items",
+                    step.from, step.count
+                ))
+            })?;
+            let mut items = from_stack.split_off(at);
+            arrange(&mut items);
+            self.stack(step.to)?.append(&mut items);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Step {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl FromStr for Step {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let words = s.split_whitespace();
+        if let Some(("move", count, "from", from, "to", to)) = words.collect_tuple() {
+            Ok(Self {
+                count: count.parse()?,
+                from: from.parse()?,
+                to: to.parse()?,
+            })
+        } else {
+            Err(Error::parse(format!("invalid step `{s}`")))
+        }
+    }
+}
+
+fn parse<R: Read>(input: Input<R>) -> Result<(Supply, Vec<Step>)> {
+    let mut blocks = input.blocks();
+    let supply: Supply = blocks
+        .next()
+        .ok_or_else(|| Error::parse("missing drawing"))??
+        .try_into()?;
+    let steps = blocks
+        .next()
+        .ok_or_else(|| Error::parse("missing steps"))??
+        .iter()
+        .map(|s| s.parse())
+        .try_collect()?;
+    Ok((supply, steps))
+}
+
+/// Starting stacks of crates and rearrangement procedure
+#[derive(Debug)]
+pub struct Procedure {
+    supply: Supply,
+    steps: Vec<Step>,
+}
+
+impl Procedure {
+    fn render_steps(&self, render: &mut dyn Render) -> Result<()> {
+        let mut supply = self.supply.clone();
+        let height = supply.0.iter().map(Vec::len).sum();
+        render.render(&supply.frame(height))?;
+        for step in &self.steps {
+            supply.apply_steps_single(slice::from_ref(step))?;
+            render.render(&supply.frame(height))?;
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Procedure {
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        let (supply, steps) = parse(input)?;
+        Ok(Self { supply, steps })
+    }
+
+    fn part_1(&self) -> Result<String> {
+        let mut supply = self.supply.clone();
+        supply.apply_steps_single(&self.steps)?;
+        Ok(supply.top_items().iter().collect())
+    }
+
+    fn part_2(&self) -> Result<String> {
+        let mut supply = self.supply.clone();
+        supply.apply_steps_multi(&self.steps)?;
+        Ok(supply.top_items().iter().collect())
+    }
+
+    fn visualize(&self, render: &mut dyn Render) -> Option<Result<()>> {
+        Some(self.render_steps(render))
+    }
+
+    /// Drawing of up to nine stacks and the given number of steps, which
+    /// never move more crates than a stack has
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let item = |rng: &mut dyn RngCore| char::from(rng.gen_range(b'A'..=b'Z'));
+        let stacks: Vec<Vec<char>> = (0..rng.gen_range(3..=9))
+            .map(|_| (0..rng.gen_range(1..=8)).map(|_| item(rng)).collect())
+            .collect();
+        let height = stacks.iter().map(Vec::len).max()?;
+        let mut input = String::new();
+        for row in (0..height).rev() {
+            let line = stacks
+                .iter()
+                .map(|stack| match stack.get(row) {
+                    Some(ch) => format!("[{ch}]"),
+                    None => "   ".to_string(),
+                })
+                .join(" ");
+            input += &(line + "\n");
+        }
+        input += &((1..=stacks.len()).map(|n| format!(" {n} ")).join(" ") + "\n\n");
+        let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+        for _ in 0..size {
+            let nonempty = heights.iter().positions(|&height| height > 0).collect_vec();
+            let from = *nonempty.choose(rng)?;
+            let to = (from + rng.gen_range(1..heights.len())) % heights.len();
+            let count = rng.gen_range(1..=heights[from]);
+            heights[from] -= count;
+            heights[to] += count;
+            let _ = writeln!(input, "move {count} from {} to {}", from + 1, to + 1);
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::Harness;
+    use crate::registry;
+
+    const INPUT: &str = "    [D]\n[N] [C]\n[Z] [M] [P]\n1   2   3\n
+        move 1 from 2 to 1
+        move 3 from 1 to 3
+        move 2 from 2 to 1
+        move 1 from 1 to 2
+    ";
+
+    fn supply_and_steps() -> (Supply, Vec<Step>) {
+        parse(Input::from(INPUT)).unwrap()
+    }
+
+    #[test]
+    fn part_1() {
+        let (mut supply, steps) = supply_and_steps();
+        supply.apply_steps_single(&steps).unwrap();
+        assert_eq!(supply.0[0], ['C']);
+        assert_eq!(supply.0[1], ['M']);
+        assert_eq!(supply.0[2], ['P', 'D', 'N', 'Z']);
+        assert_eq!(supply.top_items(), ['C', 'M', 'Z']);
+    }
+
+    #[test]
+    fn part_1_implementations() {
+        let part_1 = |apply: fn(&mut Supply, &[Step]) -> Result<()>| {
+            move |input: &str| -> Result<String> {
+                let (mut supply, steps) = parse(Input::from(input))?;
+                apply(&mut supply, &steps)?;
+                Ok(supply.top_items().iter().collect())
+            }
+        };
+        let harness = Harness::new()
+            .register("single", part_1(Supply::apply_steps_single))
+            .register("batched", part_1(Supply::apply_steps_batched));
+        harness.check("example", INPUT).unwrap();
+        let puzzle = registry::find(2022, 5).unwrap();
+        harness
+            .check_generated(0..20, |seed| puzzle.generate(seed, 200).unwrap())
+            .unwrap();
+    }
+
+    #[test]
+    fn part_2() {
+        let (mut supply, steps) = supply_and_steps();
+        supply.apply_steps_multi(&steps).unwrap();
+        assert_eq!(supply.0[0], ['M']);
+        assert_eq!(supply.0[1], ['C']);
+        assert_eq!(supply.0[2], ['P', 'Z', 'N', 'D']);
+        assert_eq!(supply.top_items(), ['M', 'C', 'D']);
+    }
+
+    #[test]
+    fn visualize() {
+        let (supply, steps) = supply_and_steps();
+        let procedure = Procedure { supply, steps };
+        let mut frames = Vec::new();
+        procedure.render_steps(&mut frames).unwrap();
+        assert_eq!(frames.len(), 5);
+        assert_eq!(frames[0].to_string(), "\n\n\n  D\nN C\nZ M P\n1 2 3\n");
+        assert_eq!(
+            frames[4].to_string(),
+            "\n\n    Z\n    N\n    D\nC M P\n1 2 3\n"
+        );
+    }
+
+    #[test]
+    fn invalid_steps() {
+        let (mut supply, _steps) = supply_and_steps();
+        let steps = ["move 4 from 1 to 2".parse().unwrap()];
+        assert!(matches!(
+            supply.clone().apply_steps_single(&steps),
+            Err(Error::InvalidState(_))
+        ));
+        assert!(matches!(
+            supply.apply_steps_multi(&steps),
+            Err(Error::InvalidState(_))
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+//! Day 6: Tuning Trouble
+
+use crate::bitset::BitSet64;
+use crate::{Error, Input, Result, Solution};
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng, RngCore};
+use std::{io::Read, iter};
+
+/// Set of bytes, a bit set for each quarter of the byte range
+#[derive(Debug, Default)]
+struct ByteSet([BitSet64; 4]);
+
+impl ByteSet {
+    fn toggle(&mut self, byte: u8) {
+        self.0[usize::from(byte / 64)].toggle(u32::from(byte % 64));
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|set| set.len() as usize).sum()
+    }
+}
+
+fn detect(size: usize, s: &str) -> &str {
+    // Toggling bytes entering and leaving the window leaves as many bytes
+    // in the set as the window has only if they're all distinct
+    let mut window = ByteSet::default();
+    let bytes = s.as_bytes();
+    for (i, &byte) in bytes.iter().enumerate() {
+        window.toggle(byte);
+        if i >= size {
+            window.toggle(bytes[i - size]);
+        }
+        if window.len() == size {
+            return &s[0..=i];
+        }
+    }
+    &s[0..0]
+}
+
+/// Push a random character that doesn't complete a marker of the given size
+fn push_without_marker(stream: &mut Vec<u8>, rng: &mut dyn RngCore, size: usize) {
+    let mut ch = rng.gen_range(b'a'..=b'z');
+    let window = &stream[stream.len().saturating_sub(size - 1)..];
+    if window.len() == size - 1 && window.iter().chain(iter::once(&ch)).all_unique() {
+        ch = *window.choose(rng).unwrap();
+    }
+    stream.push(ch);
+}
+
+/// Push a marker of the given size. It starts by repeating the last
+/// character, so no marker is completed before its end.
+fn push_marker(stream: &mut Vec<u8>, rng: &mut dyn RngCore, size: usize) {
+    let last = *stream.last().unwrap();
+    let mut others: Vec<u8> = (b'a'..=b'z').filter(|&ch| ch != last).collect();
+    others.shuffle(rng);
+    stream.push(last);
+    stream.extend(&others[..size - 1]);
+}
+
+/// Random datastream of the given length with the first start-of-packet
+/// marker ending after `packet` and the first start-of-message marker
+/// ending after `message` characters (at least 5 and `packet + 14`)
+fn generate_stream(rng: &mut dyn RngCore, len: usize, packet: usize, message: usize) -> String {
+    let mut stream = vec![rng.gen_range(b'a'..=b'z')];
+    while stream.len() < packet - 4 {
+        push_without_marker(&mut stream, rng, 4);
+    }
+    push_marker(&mut stream, rng, 4);
+    while stream.len() < message - 14 {
+        push_without_marker(&mut stream, rng, 14);
+    }
+    push_marker(&mut stream, rng, 14);
+    while stream.len() < len {
+        stream.push(rng.gen_range(b'a'..=b'z'));
+    }
+    stream.into_iter().map(char::from).collect()
+}
+
+/// Datastream buffer
+#[derive(Debug)]
+pub struct Datastream(String);
+
+impl Datastream {
+    fn marker_len(&self, size: usize) -> Result<usize> {
+        match detect(size, &self.0).len() {
+            0 => Err(Error::unsolvable(format!("no marker of size {size}"))),
+            len => Ok(len),
+        }
+    }
+}
+
+impl Solution for Datastream {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        let line = input.lines().next();
+        Ok(Self(line.ok_or_else(|| Error::parse("missing input"))??))
+    }
+
+    fn part_1(&self) -> Result<usize> {
+        self.marker_len(4)
+    }
+
+    fn part_2(&self) -> Result<usize> {
+        self.marker_len(14)
+    }
+
+    /// Datastream of the given length (at least 64) with markers at random
+    /// positions
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let len = size.max(64);
+        let packet = rng.gen_range(5..=len / 2);
+        let message = rng.gen_range(packet + 14..=len);
+        Some(generate_stream(rng, len, packet, message) + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn part_1() {
+        assert_eq!(detect(4, "mjqjpqmgbljsphdztnvjfqwrcgsmlb").len(), 7);
+        assert_eq!(detect(4, "bvwbjplbgvbhsrlpgdmjqwftvncz").len(), 5);
+        assert_eq!(detect(4, "nppdvjthqldpwncqszvftbrmjlhg").len(), 6);
+        assert_eq!(detect(4, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").len(), 10);
+        assert_eq!(detect(4, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").len(), 11);
+        assert_eq!(detect(4, "ab-cdefg").len(), 4);
+        assert_eq!(detect(4, "a-b-c-d-").len(), 0);
+    }
+
+    #[test]
+    fn part_2() {
+        assert_eq!(detect(14, "mjqjpqmgbljsphdztnvjfqwrcgsmlb").len(), 19);
+        assert_eq!(detect(14, "bvwbjplbgvbhsrlpgdmjqwftvncz").len(), 23);
+        assert_eq!(detect(14, "nppdvjthqldpwncqszvftbrmjlhg").len(), 23);
+        assert_eq!(detect(14, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").len(), 29);
+        assert_eq!(detect(14, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").len(), 26);
+    }
+
+    #[test]
+    fn generated_markers() {
+        let mut rng = StdRng::seed_from_u64(6);
+        for (packet, message) in [(5, 19), (5, 100), (50, 64), (80, 200)] {
+            let stream = generate_stream(&mut rng, 200, packet, message);
+            assert_eq!(stream.len(), 200);
+            assert_eq!(detect(4, &stream).len(), packet);
+            assert_eq!(detect(14, &stream).len(), message);
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! Day 7: No Space Left On Device
+
+use crate::{Error, Input, Result, Solution};
+use rand::{seq::SliceRandom, Rng, RngCore};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    io::Read,
+};
+
+#[derive(Debug)]
+enum Entry {
+    Directory(HashMap<String, usize>),
+    File(usize),
+}
+
+impl Entry {
+    fn new_directory() -> Entry {
+        Self::Directory(HashMap::new())
+    }
+
+    const fn new_file(size: usize) -> Entry {
+        Self::File(size)
+    }
+}
+
+/// Filesystem reconstructed from terminal output
+#[derive(Debug)]
+pub struct Filesystem {
+    entries: Vec<Entry>,
+    stack: Vec<usize>,
+    cwd: usize,
+}
+
+impl Filesystem {
+    fn new() -> Self {
+        Self {
+            entries: vec![Entry::new_directory()],
+            stack: vec![],
+            cwd: 0,
+        }
+    }
+
+    fn cwd(&mut self) -> &mut HashMap<String, usize> {
+        match self.entries[self.cwd] {
+            Entry::Directory(ref mut dir) => dir,
+            Entry::File(_) => unreachable!(),
+        }
+    }
+
+    fn cd(&mut self, name: &str) -> Result<()> {
+        match name {
+            "/" => {
+                self.stack.clear();
+                self.cwd = 0;
+            }
+            ".." => {
+                self.cwd = self
+                    .stack
+                    .pop()
+                    .ok_or_else(|| Error::invalid_state("cd .. in root directory"))?;
+            }
+            _ => {
+                let id = *self
+                    .cwd()
+                    .get(name)
+                    .ok_or_else(|| Error::invalid_state(format!("no such entry `{name}`")))?;
+                if !matches!(self.entries[id], Entry::Directory(_)) {
+                    return Err(Error::invalid_state(format!("not a directory `{name}`")));
+                }
+                self.stack.push(self.cwd);
+                self.cwd = id;
+            }
+        }
+        Ok(())
+    }
+
+    fn mknode(&mut self, name: &str, entry: Entry) {
+        self.entries.push(entry);
+        let id = self.entries.len() - 1;
+        self.cwd().insert(name.to_string(), id);
+    }
+
+    fn mkdir(&mut self, name: &str) {
+        self.mknode(name, Entry::new_directory());
+    }
+
+    fn mkfile(&mut self, name: &str, size: usize) {
+        self.mknode(name, Entry::new_file(size));
+    }
+
+    fn du_id(&self, id: usize) -> usize {
+        match self.entries[id] {
+            Entry::Directory(ref dir) => dir.values().map(|id| self.du_id(*id)).sum(),
+            Entry::File(size) => size,
+        }
+    }
+
+    #[allow(unused)]
+    fn du(&self) -> usize {
+        self.du_id(self.cwd)
+    }
+
+    /// Total size of all files
+    pub(crate) fn used_size(&self) -> usize {
+        self.du_id(0)
+    }
+
+    /// Sizes of all directories, including their subdirectories
+    pub(crate) fn dir_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(id, entry)| matches!(entry, Entry::Directory(_)).then_some(id))
+            .map(|id| self.du_id(id))
+    }
+
+    pub(crate) fn sum_of_dir_sizes(&self, max_dir_size: usize) -> usize {
+        self.dir_sizes()
+            .filter(|size| *size <= max_dir_size)
+            .sum::<usize>()
+    }
+
+    pub(crate) fn size_of_dir_to_delete(
+        &self,
+        total_size: usize,
+        desired_free_size: usize,
+    ) -> Result<usize> {
+        let free_size = total_size
+            .checked_sub(self.used_size())
+            .ok_or_else(|| Error::invalid_state("used size exceeds total size"))?;
+        let size_to_free_up = desired_free_size.saturating_sub(free_size);
+        self.dir_sizes()
+            .filter(|size| *size >= size_to_free_up)
+            .min()
+            .ok_or_else(|| Error::unsolvable("no directory is large enough"))
+    }
+
+    fn add_entry(&mut self, line: &str) -> Result<()> {
+        let mut parts = line.split_whitespace();
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("dir"), Some(name), None) => self.mkdir(name),
+            (Some(size), Some(name), None) => self.mkfile(name, size.parse()?),
+            _ => return Err(Error::parse(format!("invalid output `{line}`"))),
+        }
+        Ok(())
+    }
+
+    /// Apply command and its output, starting at the given line
+    fn apply(&mut self, block: &[String], line: usize) -> Result<()> {
+        let (command, output) = block
+            .split_first()
+            .ok_or_else(|| Error::parse("missing command"))?;
+        let mut parts = command.split_whitespace();
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some("$"), Some("cd"), Some(name), None) if output.is_empty() => self.cd(name),
+            (Some("$"), Some("ls"), None, None) => output
+                .iter()
+                .zip(line + 1..)
+                .try_for_each(|(output, line)| self.add_entry(output).map_err(|e| e.at_line(line))),
+            _ => Err(Error::parse(format!(
+                "invalid command `{}`",
+                command.trim()
+            ))),
+        }
+        .map_err(|e| e.at_line(line))
+    }
+}
+
+fn parse<R: Read>(input: Input<R>) -> Result<Filesystem> {
+    let mut fs = Filesystem::new();
+    let mut line = 1;
+    for block in input.split_by(|line| line.trim_start().starts_with('$')) {
+        let block = block?;
+        fs.apply(&block, line)?;
+        line += block.len();
+    }
+    fs.cd("/")?;
+    Ok(fs)
+}
+
+/// Append terminal output of listing the given directory and exploring its
+/// subdirectories, given the subdirectories of each directory
+fn generate_listing(
+    rng: &mut dyn RngCore,
+    subdirs: &[Vec<usize>],
+    dir: usize,
+    max_file_size: usize,
+    output: &mut String,
+) {
+    let mut names = HashSet::new();
+    let mut unique_name = |rng: &mut dyn RngCore, extension: bool| loop {
+        let mut name: String = (0..rng.gen_range(1..=8))
+            .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+            .collect();
+        if extension {
+            name.push('.');
+            name.extend((0..3).map(|_| char::from(rng.gen_range(b'a'..=b'z'))));
+        }
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+    let dir_names: Vec<String> = subdirs[dir]
+        .iter()
+        .map(|_| unique_name(rng, false))
+        .collect();
+    let mut entries: Vec<String> = dir_names.iter().map(|name| format!("dir {name}")).collect();
+    for _ in 0..rng.gen_range(0..=4) {
+        let extension = rng.gen_bool(0.5);
+        let name = unique_name(rng, extension);
+        entries.push(format!("{} {name}", rng.gen_range(1..=max_file_size)));
+    }
+    entries.shuffle(rng);
+    output.push_str("$ ls\n");
+    for entry in entries {
+        let _ = writeln!(output, "{entry}");
+    }
+    for (&subdir, name) in subdirs[dir].iter().zip(&dir_names) {
+        let _ = writeln!(output, "$ cd {name}");
+        generate_listing(rng, subdirs, subdir, max_file_size, output);
+        output.push_str("$ cd ..\n");
+    }
+}
+
+impl Solution for Filesystem {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        parse(input)
+    }
+
+    fn part_1(&self) -> Result<usize> {
+        Ok(self.sum_of_dir_sizes(100_000))
+    }
+
+    fn part_2(&self) -> Result<usize> {
+        self.size_of_dir_to_delete(70_000_000, 30_000_000)
+    }
+
+    /// Terminal output of exploring a filesystem with the given number of
+    /// directories. Directories have up to 4 files, which are small enough
+    /// to fit on the disk.
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let dirs = size.max(1);
+        let mut subdirs = vec![Vec::new(); dirs];
+        for dir in 1..dirs {
+            subdirs[rng.gen_range(0..dir)].push(dir);
+        }
+        let max_file_size = (60_000_000 / (dirs * 4)).clamp(1, 300_000);
+        let mut output = "$ cd /\n".to_string();
+        generate_listing(rng, &subdirs, 0, max_file_size, &mut output);
+        Some(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn fs() -> Filesystem {
+        const INPUT: &str = "$ cd /
+            $ ls
+            dir a
+            14848514 b.txt
+            8504156 c.dat
+            dir d
+            $ cd a
+            $ ls
+            dir e
+            29116 f
+            2557 g
+            62596 h.lst
+            $ cd e
+            $ ls
+            584 i
+            $ cd ..
+            $ cd ..
+            $ cd d
+            $ ls
+            4060174 j
+            8033020 d.log
+            5626152 d.ext
+            7214296 k";
+        parse(Input::from(INPUT)).unwrap()
+    }
+
+    #[test]
+    fn part_1() {
+        let mut fs = fs();
+        fs.cd("a").unwrap();
+        fs.cd("e").unwrap();
+        assert_eq!(fs.du(), 584);
+        fs.cd("..").unwrap();
+        assert_eq!(fs.du(), 94853);
+        fs.cd("..").unwrap();
+        fs.cd("d").unwrap();
+        assert_eq!(fs.du(), 24_933_642);
+        fs.cd("..").unwrap();
+        assert_eq!(fs.du(), 48_381_165);
+
+        assert_eq!(fs.sum_of_dir_sizes(100_000), 95437);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse(Input::from("$ cd /\n$ ls\ndir a\nx\n")).unwrap_err();
+        assert_eq!(err.to_string(), "Parse error at line 4: invalid output `x`");
+        let err = parse(Input::from("$ cd /\n$ cd a\ndir b\n")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parse error at line 2: invalid command `$ cd a`"
+        );
+        let err = parse(Input::from("$ cd /\n$ cd a\n")).unwrap_err();
+        assert!(matches!(err, Error::InvalidState(_)));
+    }
+
+    #[test]
+    fn part_2() {
+        let fs = fs();
+        assert_eq!(
+            fs.size_of_dir_to_delete(70_000_000, 30_000_000).unwrap(),
+            24_933_642
+        );
+    }
+
+    #[test]
+    fn generated_filesystem() {
+        let input = Filesystem::generate(&mut StdRng::seed_from_u64(7), 500).unwrap();
+        let fs = parse(Input::from(input.as_str())).unwrap();
+        assert_eq!(fs.dir_sizes().count(), 500);
+        assert!(fs.du_id(0) <= 70_000_000);
+        assert!(fs.size_of_dir_to_delete(70_000_000, 30_000_000).is_ok());
+    }
+}
@@ -0,0 +1,235 @@
+//! Day 8: Treetop Tree House
+
+use crate::geometry::{BoundingBox, Direction, Point2};
+use crate::visualize::{Frame, Render};
+use crate::{Error, Input, Result, Solution};
+use rand::{Rng, RngCore};
+use std::{
+    collections::{HashMap, HashSet},
+    io::Read,
+    iter,
+};
+
+/// Map of tree heights
+#[derive(Debug)]
+pub struct Grid {
+    bounds: BoundingBox<Point2<usize>>,
+    heightmap: HashMap<Point2<usize>, u8>,
+}
+
+impl<R: Read> TryFrom<Input<R>> for Grid {
+    type Error = Error;
+
+    fn try_from(input: Input<R>) -> Result<Self> {
+        let mut heightmap = HashMap::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, ch) in line?.chars().enumerate() {
+                if !ch.is_ascii_digit() {
+                    let reason = format!("invalid height `{ch}` in column {}", x + 1);
+                    return Err(Error::parse(reason).at_line(y + 1));
+                }
+                heightmap.insert(Point2::new(x, y), ch as u8);
+            }
+        }
+        let bounds = BoundingBox::from_points(heightmap.keys().copied())
+            .ok_or_else(|| Error::parse("no trees"))?;
+        Ok(Self { bounds, heightmap })
+    }
+}
+
+impl Grid {
+    fn get_height(&self, tree: Point2<usize>) -> u8 {
+        self.heightmap.get(&tree).copied().unwrap_or(0)
+    }
+
+    /// Trees in the given direction, starting with the nearest one
+    fn ray(
+        &self,
+        tree: Point2<usize>,
+        direction: Direction,
+    ) -> impl Iterator<Item = Point2<usize>> + '_ {
+        tree.ray(direction)
+            .take_while(|&other| self.bounds.contains(other))
+    }
+
+    fn is_visible(&self, tree: Point2<usize>) -> bool {
+        let height = self.get_height(tree);
+        Direction::ALL.into_iter().any(|direction| {
+            self.ray(tree, direction)
+                .all(|other| self.get_height(other) < height)
+        })
+    }
+
+    /// Trees visible from outside, found by sweeping over each row and
+    /// column from both sides while keeping track of the tallest tree
+    fn visible_sweep(&self) -> HashSet<Point2<usize>> {
+        let mut visible = HashSet::new();
+        for direction in Direction::ALL {
+            let opposite = direction.opposite();
+            let edge = self
+                .bounds
+                .points()
+                .filter(|&tree| self.ray(tree, opposite).next().is_none());
+            for start in edge {
+                let mut tallest = None;
+                for tree in iter::once(start).chain(self.ray(start, direction)) {
+                    let height = self.get_height(tree);
+                    if tallest.is_none_or(|tallest| height > tallest) {
+                        visible.insert(tree);
+                        tallest = Some(height);
+                    }
+                }
+            }
+        }
+        visible
+    }
+
+    fn count_visible(&self) -> usize {
+        self.visible_sweep().len()
+    }
+
+    fn visible_trees(&self) -> Frame {
+        let mut frame = Frame::new(self.bounds.width(), self.bounds.height());
+        for tree in self.bounds.points().filter(|&tree| self.is_visible(tree)) {
+            frame.set(tree.x, tree.y, self.get_height(tree) as char);
+        }
+        frame
+    }
+
+    /// Number of trees seen in the given direction, up to the first tree
+    /// at least as tall as the given tree or the edge
+    fn viewing_distance(&self, tree: Point2<usize>, direction: Direction) -> usize {
+        let height = self.get_height(tree);
+        let mut distance = 0;
+        for other in self.ray(tree, direction) {
+            distance += 1;
+            if self.get_height(other) >= height {
+                break;
+            }
+        }
+        distance
+    }
+
+    fn scenic_score(&self, tree: Point2<usize>) -> usize {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| self.viewing_distance(tree, direction))
+            .product()
+    }
+
+    fn find_best_scenic_score(&self) -> usize {
+        self.bounds
+            .points()
+            .map(|tree| self.scenic_score(tree))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Solution for Grid {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        input.try_into()
+    }
+
+    fn part_1(&self) -> Result<usize> {
+        Ok(self.count_visible())
+    }
+
+    fn part_2(&self) -> Result<usize> {
+        Ok(self.find_best_scenic_score())
+    }
+
+    fn visualize(&self, render: &mut dyn Render) -> Option<Result<()>> {
+        Some(render.render(&self.visible_trees()).map_err(Into::into))
+    }
+
+    /// Square grid with the given number of rows and columns
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let size = size.max(1);
+        let row = |rng: &mut dyn RngCore| {
+            (0..size)
+                .map(|_| char::from(rng.gen_range(b'0'..=b'9')))
+                .chain(iter::once('\n'))
+                .collect::<String>()
+        };
+        Some((0..size).map(|_| row(rng)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::Harness;
+    use crate::registry;
+
+    const INPUT: &str = "30373\n25512\n65332\n33549\n35390\n";
+
+    fn grid() -> Grid {
+        Input::from(INPUT).try_into().unwrap()
+    }
+
+    #[test]
+    fn part_1() {
+        let grid = grid();
+
+        for x in 0..=4 {
+            assert!(grid.is_visible(Point2::new(x, 0))); // top row
+            assert!(grid.is_visible(Point2::new(x, 4))); // bottom row
+        }
+        for y in 0..=4 {
+            assert!(grid.is_visible(Point2::new(0, y))); // left column
+            assert!(grid.is_visible(Point2::new(4, y))); // right column
+        }
+
+        assert!(grid.is_visible(Point2::new(1, 1))); // top-left 5
+        assert!(grid.is_visible(Point2::new(2, 1))); // top-middle 5
+        assert!(!grid.is_visible(Point2::new(3, 1))); // top-right 1
+        assert!(grid.is_visible(Point2::new(1, 2))); // left-middle 5
+        assert!(!grid.is_visible(Point2::new(2, 2))); // center 3
+        assert!(grid.is_visible(Point2::new(3, 2))); // right-middle 3
+        assert!(!grid.is_visible(Point2::new(1, 3))); // bottom-left 3
+        assert!(grid.is_visible(Point2::new(2, 3))); // bottom-middle 5
+        assert!(!grid.is_visible(Point2::new(3, 3))); // bottom-right 4
+
+        assert_eq!(grid.count_visible(), 21);
+    }
+
+    #[test]
+    fn empty_grid() {
+        assert!(Grid::try_from(Input::from("")).is_err());
+        assert!(Grid::try_from(Input::from("\n")).is_err());
+        let grid = Grid::try_from(Input::from("5\n")).unwrap();
+        assert_eq!(grid.count_visible(), 1);
+    }
+
+    #[test]
+    fn part_2() {
+        let grid = grid();
+
+        assert_eq!(grid.scenic_score(Point2::new(2, 1)), 4);
+        assert_eq!(grid.scenic_score(Point2::new(2, 3)), 8);
+
+        assert_eq!(grid.find_best_scenic_score(), 8);
+    }
+
+    #[test]
+    fn part_1_implementations() {
+        let harness = Harness::new()
+            .register("brute force", |input| {
+                let grid = Grid::try_from(Input::from(input))?;
+                let trees = grid.bounds.points();
+                Ok(trees.filter(|&tree| grid.is_visible(tree)).count())
+            })
+            .register("sweep", |input| {
+                Ok(Grid::try_from(Input::from(input))?.count_visible())
+            });
+        harness.check("example", INPUT).unwrap();
+        let puzzle = registry::find(2022, 8).unwrap();
+        harness
+            .check_generated(0..20, |seed| puzzle.generate(seed, 30).unwrap())
+            .unwrap();
+    }
+}
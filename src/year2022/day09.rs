@@ -0,0 +1,235 @@
+//! Day 9: Rope Bridge
+
+use crate::cycle::Simulation;
+use crate::geometry::{Direction, Point2};
+use crate::visualize::{Frame, Render};
+use crate::{Error, Input, Result, Solution};
+use rand::{Rng, RngCore};
+use std::{collections::HashSet, io::Read, str::FromStr};
+
+#[derive(Debug)]
+struct Motion {
+    direction: Direction,
+    distance: usize,
+}
+
+impl FromStr for Motion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split_whitespace();
+        let mut part = || parts.next().ok_or_else(|| Error::parse("missing column"));
+        Ok(Self {
+            direction: part()?.parse()?,
+            distance: part()?.parse()?,
+        })
+    }
+}
+
+/// Offset of a step in the given direction. Unlike screen coordinates of
+/// the geometry module, puzzle coordinates grow upwards.
+fn offset(direction: Direction) -> Point2<i32> {
+    let Point2 { x, y } = direction.offset();
+    Point2::new(x, -y)
+}
+
+/// Rope whose head follows a series of motions, starting over with the
+/// first motion after the last one
+#[derive(Debug)]
+struct Rope<'a> {
+    motions: &'a [Motion],
+    next_motion: usize,
+    knots: Vec<Point2<i32>>,
+    visited: HashSet<Point2<i32>>,
+}
+
+impl<'a> Rope<'a> {
+    fn new(num_knots: usize, motions: &'a [Motion]) -> Self {
+        Self {
+            motions,
+            next_motion: 0,
+            knots: vec![Point2::default(); num_knots],
+            visited: HashSet::new(),
+        }
+    }
+
+    fn from_motions(num_knots: usize, motions: &'a [Motion]) -> Self {
+        let mut rope = Self::new(num_knots, motions);
+        rope.apply();
+        rope
+    }
+
+    /// Apply all motions once
+    fn apply(&mut self) {
+        for _ in 0..self.motions.len() {
+            self.apply_next();
+        }
+    }
+
+    /// Apply the next motion
+    fn apply_next(&mut self) {
+        let Some(motion) = self.motions.get(self.next_motion) else {
+            return;
+        };
+        for _ in 0..motion.distance {
+            self.step(motion.direction);
+        }
+        self.next_motion = (self.next_motion + 1) % self.motions.len();
+    }
+
+    fn step(&mut self, direction: Direction) {
+        self.knots[0] += offset(direction);
+        for k in 1..self.knots.len() {
+            let (prev, knot) = (self.knots[k - 1], self.knots[k]);
+            if knot.chebyshev(prev) > 1 {
+                self.knots[k] = knot.step_towards(prev);
+            }
+        }
+        self.visited.insert(*self.knots.last().unwrap());
+    }
+
+    /// Frame of the given size centered on the head, showing knots and
+    /// positions visited by the tail
+    fn frame(&self, width: usize, height: usize) -> Frame {
+        let half = |n: usize| i32::try_from(n / 2).unwrap_or(i32::MAX);
+        let (left, top) = (
+            self.knots[0].x - half(width),
+            self.knots[0].y + half(height),
+        );
+        let mut frame = Frame::new(width, height);
+        let mut set = |pos: Point2<i32>, ch| {
+            if let (Ok(x), Ok(y)) = (usize::try_from(pos.x - left), usize::try_from(top - pos.y)) {
+                frame.set(x, y, ch);
+            }
+        };
+        for pos in &self.visited {
+            set(*pos, '#');
+        }
+        for (k, pos) in self.knots.iter().enumerate().rev() {
+            let ch = match k {
+                0 => 'H',
+                k => char::from_digit(u32::try_from(k % 10).unwrap_or(0), 10).unwrap_or('?'),
+            };
+            set(*pos, ch);
+        }
+        frame
+    }
+}
+
+impl Simulation for Rope<'_> {
+    /// Next motion and positions of knots relative to the head
+    type State = (usize, Vec<Point2<i32>>);
+
+    fn state(&self) -> Self::State {
+        let head = self.knots[0];
+        let knots = self.knots.iter().map(|&knot| knot - head).collect();
+        (self.next_motion, knots)
+    }
+
+    fn step(&mut self) {
+        self.apply_next();
+    }
+}
+
+/// Series of motions of the rope's head
+#[derive(Debug)]
+pub struct Motions(Vec<Motion>);
+
+impl Solution for Motions {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        Ok(Self(input.lines_parse_all()?))
+    }
+
+    fn part_1(&self) -> Result<usize> {
+        Ok(Rope::from_motions(2, &self.0).visited.len())
+    }
+
+    fn part_2(&self) -> Result<usize> {
+        Ok(Rope::from_motions(10, &self.0).visited.len())
+    }
+
+    fn visualize(&self, render: &mut dyn Render) -> Option<Result<()>> {
+        let mut rope = Rope::new(10, &self.0);
+        let res = self.0.iter().try_for_each(|motion| {
+            (0..motion.distance).try_for_each(|_| {
+                rope.step(motion.direction);
+                render.render(&rope.frame(40, 20))
+            })
+        });
+        Some(res.map_err(Into::into))
+    }
+
+    /// Series of the given number of motions
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let motion = |rng: &mut dyn RngCore| {
+            let direction = ['U', 'R', 'D', 'L'][rng.gen_range(0..4)];
+            format!("{direction} {}\n", rng.gen_range(1..=20))
+        };
+        Some((0..size).map(|_| motion(rng)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cycle;
+    use itertools::Itertools;
+
+    const INPUT2: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n";
+    const INPUT10: &str = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n";
+
+    fn motions(input: &str) -> Vec<Motion> {
+        Input::from(input).lines_parse().try_collect().unwrap()
+    }
+
+    #[test]
+    fn part_1() {
+        let motions = motions(INPUT2);
+        let rope = Rope::from_motions(2, &motions);
+        assert_eq!(rope.visited.len(), 13);
+        assert!(rope.visited.contains(&Point2::new(1, 0)));
+        assert!(rope.visited.contains(&Point2::new(2, 0)));
+        assert!(rope.visited.contains(&Point2::new(3, 0)));
+        assert!(rope.visited.contains(&Point2::new(4, 1)));
+        assert!(rope.visited.contains(&Point2::new(1, 2)));
+        assert!(rope.visited.contains(&Point2::new(2, 2)));
+        assert!(rope.visited.contains(&Point2::new(3, 2)));
+        assert!(rope.visited.contains(&Point2::new(4, 2)));
+        assert!(rope.visited.contains(&Point2::new(3, 3)));
+        assert!(rope.visited.contains(&Point2::new(4, 3)));
+        assert!(rope.visited.contains(&Point2::new(2, 4)));
+        assert!(rope.visited.contains(&Point2::new(3, 4)));
+    }
+
+    #[test]
+    fn part_2() {
+        let motions = motions(INPUT10);
+        let rope = Rope::from_motions(10, &motions);
+        assert_eq!(rope.visited.len(), 36);
+    }
+
+    #[test]
+    fn repeated_motions() {
+        let motions = motions(INPUT10);
+        let tail_x = |rope: &Rope| i128::from(rope.knots[9].x);
+        let mut brute_force = Rope::new(10, &motions);
+        for _ in 0..1000 {
+            brute_force.apply_next();
+        }
+        let mut rope = Rope::new(10, &motions);
+        let cycle = cycle::find(&mut rope);
+        assert_eq!(cycle.len % motions.len(), 0);
+        let mut rope = Rope::new(10, &motions);
+        assert_eq!(
+            cycle::extrapolate(&mut rope, 1000, tail_x),
+            tail_x(&brute_force)
+        );
+        assert_eq!(rope.state(), brute_force.state());
+        let mut rope = Rope::new(10, &motions);
+        let tail_x = cycle::extrapolate(&mut rope, 1_000_000_000_000, tail_x);
+        assert!(tail_x < -1_000_000_000_000);
+    }
+}
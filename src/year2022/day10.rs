@@ -0,0 +1,271 @@
+//! Day 10: Cathode-Ray Tube
+
+use crate::cycle::Simulation;
+use crate::visualize::{Frame, Render};
+use crate::{ocr, Answer, Error, Input, Result, Solution};
+use rand::{Rng, RngCore};
+use std::{io::Read, str::FromStr};
+
+#[derive(Debug)]
+pub(crate) enum Instruction {
+    AddX(i32),
+    NoOp,
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split_whitespace();
+        let mut token = move || -> Result<&str> {
+            parts
+                .next()
+                .ok_or_else(|| Error::parse(format!("missing token in `{s}`")))
+        };
+        let arg = |s: &str| -> Result<i32> { Ok(s.parse()?) };
+        match token()? {
+            "addx" => Ok(Instruction::AddX(arg(token()?)?)),
+            "noop" => Ok(Instruction::NoOp),
+            op => Err(Error::parse(format!("invalid instruction `{op}`"))),
+        }
+    }
+}
+
+impl Instruction {
+    fn cycles(&self) -> usize {
+        match self {
+            Self::AddX(_) => 2,
+            Self::NoOp => 1,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Cpu<'a> {
+    pub(crate) instructions: &'a [Instruction],
+    pub(crate) pc: usize,
+    pub(crate) cycles: usize,
+    pub(crate) x: i32,
+}
+
+impl<'a> Cpu<'a> {
+    pub(crate) fn new(instructions: &'a [Instruction]) -> Self {
+        Self {
+            instructions,
+            pc: 0,
+            cycles: 0,
+            x: 1,
+        }
+    }
+
+    fn next_instruction(&self) -> Option<&Instruction> {
+        self.instructions.get(self.pc)
+    }
+
+    fn next_instruction_cycles(&self) -> usize {
+        self.next_instruction().map_or(0, Instruction::cycles)
+    }
+
+    pub(crate) fn step(&mut self) -> usize {
+        let Some(instruction) = self.instructions.get(self.pc) else {
+            return 0;
+        };
+        match instruction {
+            Instruction::NoOp => (),
+            Instruction::AddX(a) => self.x += *a,
+        }
+        let cycles = instruction.cycles();
+        self.pc += 1;
+        self.cycles += cycles;
+        cycles
+    }
+
+    pub(crate) fn run(&mut self) -> (i32, String) {
+        let mut signal_strength = 0;
+        let mut crt = String::new();
+        loop {
+            let next_cycles = self.next_instruction_cycles();
+            if (self.cycles + 20) / 40 < (self.cycles + next_cycles + 20) / 40 {
+                // Programs running for more than `i32::MAX` cycles aren't supported
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                let cycle = ((self.cycles + 20) / 40 * 40 + 20) as i32;
+                signal_strength += cycle * self.x;
+            }
+            for c in 0..next_cycles {
+                let pos = (self.cycles + c) % 40;
+                let sprite = self.x - 1..=self.x + 1;
+                if i32::try_from(pos).is_ok_and(|pos| sprite.contains(&pos)) {
+                    crt.push('#');
+                } else {
+                    crt.push(' ');
+                }
+                if pos == 39 {
+                    crt.push('\n');
+                }
+            }
+            if self.step() == 0 {
+                return (signal_strength, crt);
+            }
+        }
+    }
+}
+
+impl Simulation for Cpu<'_> {
+    /// Next instruction and value of the X register. Only programs that
+    /// leave X unchanged repeat their state.
+    type State = (usize, i32);
+
+    fn state(&self) -> Self::State {
+        (self.pc, self.x)
+    }
+
+    /// Run the next instruction, starting over with the first instruction
+    /// after the last one
+    fn step(&mut self) {
+        if Cpu::step(self) > 0 && self.pc == self.instructions.len() {
+            self.pc = 0;
+        }
+    }
+}
+
+/// Program of CPU instructions
+#[derive(Debug)]
+pub struct Program(pub(crate) Vec<Instruction>);
+
+impl Solution for Program {
+    type Answer1 = i32;
+    type Answer2 = Answer;
+
+    fn parse<R: Read>(input: Input<R>) -> Result<Self> {
+        Ok(Self(input.lines_parse_all()?))
+    }
+
+    fn part_1(&self) -> Result<i32> {
+        Ok(Cpu::new(&self.0).run().0)
+    }
+
+    fn part_2(&self) -> Result<Answer> {
+        let crt = Cpu::new(&self.0).run().1;
+        // Answer with the image if letters can't be recognized
+        let Ok(image) = crt.parse::<Answer>();
+        Ok(ocr::recognize(&crt).map_or(image, Answer::from))
+    }
+
+    fn visualize(&self, render: &mut dyn Render) -> Option<Result<()>> {
+        let crt = Cpu::new(&self.0).run().1;
+        let mut frame = Frame::new(40, 6);
+        let res = crt.lines().enumerate().try_for_each(|(y, line)| {
+            line.chars().enumerate().try_for_each(|(x, ch)| {
+                frame.set(x, y, ch);
+                render.render(&frame)
+            })
+        });
+        Some(res.map_err(Into::into))
+    }
+
+    /// Program of the given number of instructions, keeping the sprite
+    /// mostly on screen
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        let mut x = 1;
+        let mut instruction = |rng: &mut dyn RngCore| {
+            if rng.gen_bool(0.3) {
+                return "noop\n".to_string();
+            }
+            let mut v = rng.gen_range(-15..=15);
+            if !(-2..=42).contains(&(x + v)) {
+                v = -v;
+            }
+            x += v;
+            format!("addx {v}\n")
+        };
+        Some((0..size).map(|_| instruction(rng)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cycle, snapshot};
+    use itertools::Itertools;
+
+    fn instructions() -> Vec<Instruction> {
+        const INPUT: &str = "addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\n\
+            addx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\n\
+            addx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\n\
+            noop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\n\
+            addx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\n\
+            noop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\n\
+            addx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\n\
+            noop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\n\
+            addx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\n\
+            addx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\n\
+            addx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\n\
+            noop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\n\
+            addx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\n\
+            noop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop\n";
+        Input::from(INPUT).lines_parse().try_collect().unwrap()
+    }
+
+    #[test]
+    fn part_1() {
+        let instructions = instructions();
+        let mut cpu = Cpu::new(&instructions);
+        assert_eq!(cpu.run().0, 13140);
+    }
+
+    #[test]
+    fn repeated_program() {
+        let instructions: Vec<Instruction> = Input::from("addx 3\nnoop\naddx -3\n")
+            .lines_parse()
+            .try_collect()
+            .unwrap();
+        let cycles = |cpu: &Cpu| cpu.cycles as i128;
+        let mut cpu = Cpu::new(&instructions);
+        assert_eq!(cycle::find(&mut cpu), cycle::Cycle { start: 0, len: 3 });
+        let mut brute_force = Cpu::new(&instructions);
+        for _ in 0..1000 {
+            Simulation::step(&mut brute_force);
+        }
+        let mut cpu = Cpu::new(&instructions);
+        assert_eq!(
+            cycle::extrapolate(&mut cpu, 1000, cycles),
+            cycles(&brute_force)
+        );
+        assert_eq!(cpu.state(), brute_force.state());
+        let mut cpu = Cpu::new(&instructions);
+        let total = cycle::extrapolate(&mut cpu, 3_000_000_000_000, cycles);
+        assert_eq!(total, 5_000_000_000_000);
+    }
+
+    #[test]
+    fn part_2() {
+        let instructions = instructions();
+        let mut cpu = Cpu::new(&instructions);
+        snapshot::assert_matches("2022/day10-part2", &cpu.run().1);
+    }
+
+    #[test]
+    fn part_2_image() {
+        let answer = Program(instructions()).part_2().unwrap();
+        assert!(matches!(answer, Answer::Image(ref rows) if rows.len() == 6));
+        assert!(answer.to_string().starts_with("##..##..##"));
+    }
+
+    #[test]
+    fn visualize() {
+        let program = Program(instructions());
+        let mut frames = Vec::new();
+        program.visualize(&mut frames).unwrap().unwrap();
+        assert_eq!(frames.len(), 240);
+        assert_eq!(frames[0].to_string(), "#\n\n\n\n\n\n");
+        assert_eq!(
+            frames[239].to_string(),
+            Cpu::new(&program.0)
+                .run()
+                .1
+                .lines()
+                .map(|line| line.trim_end().to_string() + "\n")
+                .collect::<String>()
+        );
+    }
+}
@@ -0,0 +1,28 @@
+//! Advent of Code 2022
+
+use crate::Puzzle;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+
+/// Puzzle solutions of 2022
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<day01::Calories>(2022, 1),
+    Puzzle::new::<day02::StrategyGuide>(2022, 2),
+    Puzzle::new::<day03::Rucksacks>(2022, 3),
+    Puzzle::new::<day04::Assignments>(2022, 4),
+    Puzzle::new::<day05::Procedure>(2022, 5),
+    Puzzle::new::<day06::Datastream>(2022, 6),
+    Puzzle::new::<day07::Filesystem>(2022, 7),
+    Puzzle::new::<day08::Grid>(2022, 8),
+    Puzzle::new::<day09::Motions>(2022, 9),
+    Puzzle::new::<day10::Program>(2022, 10),
+];
@@ -0,0 +1,80 @@
+/* Advent of Code: C interface test, compiled and run by tests/capi.rs */
+
+#include "aoc.h"
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#define CHECK(cond)                                                          \
+    do {                                                                     \
+        if (!(cond)) {                                                       \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, \
+                    #cond);                                                  \
+            exit(1);                                                         \
+        }                                                                    \
+    } while (0)
+
+static const char CALORIES[] =
+    "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+static AocStatus solve(uint32_t day, uint32_t part, const char *input, AocBuffer *out) {
+    return aoc_solve(day, part, (const uint8_t *)input, strlen(input), out);
+}
+
+static void test_answers(void) {
+    char data[64];
+    AocBuffer out = {data, sizeof(data), 0};
+    CHECK(solve(1, 1, CALORIES, &out) == AOC_OK);
+    CHECK(strcmp(data, "24000") == 0 && out.len == 5);
+    CHECK(solve(1, 2, CALORIES, &out) == AOC_OK);
+    CHECK(strcmp(data, "45000") == 0 && out.len == 5);
+}
+
+static void test_buffer_size(void) {
+    AocBuffer query = {NULL, 0, 0};
+    CHECK(solve(1, 1, CALORIES, &query) == AOC_BUFFER_TOO_SMALL);
+    CHECK(query.len == 5);
+
+    char small[3];
+    AocBuffer out = {small, sizeof(small), 0};
+    CHECK(solve(1, 1, CALORIES, &out) == AOC_BUFFER_TOO_SMALL);
+    CHECK(strcmp(small, "24") == 0 && out.len == 5);
+
+    char *data = malloc(query.len + 1);
+    CHECK(data != NULL);
+    AocBuffer exact = {data, query.len + 1, 0};
+    CHECK(solve(1, 1, CALORIES, &exact) == AOC_OK);
+    CHECK(strcmp(data, "24000") == 0);
+    free(data);
+}
+
+static void test_errors(void) {
+    char data[128];
+    AocBuffer out = {data, sizeof(data), 0};
+    CHECK(solve(26, 1, CALORIES, &out) == AOC_UNKNOWN_PUZZLE);
+    CHECK(strcmp(data, "no puzzle 2022 day 26") == 0);
+    CHECK(solve(1, 3, CALORIES, &out) == AOC_UNKNOWN_PUZZLE);
+    CHECK(strcmp(data, "no part 3 in 2022 day 1") == 0);
+    CHECK(solve(1, 1, "1000\nx\n", &out) == AOC_INVALID_INPUT);
+    CHECK(strncmp(data, "Parse error at line 2", 21) == 0 && out.len == strlen(data));
+    CHECK(solve(1, 1, "\xff\n", &out) == AOC_INVALID_INPUT);
+    CHECK(aoc_solve(1, 1, NULL, 1, &out) == AOC_NULL_POINTER);
+    CHECK(strcmp(data, "input is null") == 0);
+    CHECK(aoc_solve(1, 1, (const uint8_t *)CALORIES, 1, NULL) == AOC_NULL_POINTER);
+}
+
+static void test_status_messages(void) {
+    CHECK(strcmp(aoc_status_message(AOC_OK), "answer was written to the output buffer") == 0);
+    CHECK(strcmp(aoc_status_message(AOC_PANIC), "solution panicked") == 0);
+    CHECK(strcmp(aoc_status_message(-1), "unknown status") == 0);
+}
+
+int main(void) {
+    test_answers();
+    test_buffer_size();
+    test_errors();
+    test_status_messages();
+    puts("ok");
+    return 0;
+}
@@ -0,0 +1,49 @@
+//! C interface test: compiles `tests/c/capi.c` against the shared library
+//! and the generated header and runs it
+
+#![cfg(unix)]
+
+use advent_of_code_2022::capi::HEADER_PATH;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Shared library built for this test, next to the test executable. It's
+/// linked by path, so that no other build of the library is loaded.
+fn library() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    let name = format!(
+        "{}advent_of_code_2022{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    );
+    exe.with_file_name(name)
+}
+
+#[test]
+fn c_program() {
+    let source = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/c/capi.c");
+    let include_dir = Path::new(HEADER_PATH).parent().unwrap();
+    let library = library();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("capi");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-o"])
+        .arg(&program)
+        .arg(source)
+        .arg("-I")
+        .arg(include_dir)
+        .arg(&library)
+        .status()
+        .expect("can't run C compiler");
+    assert!(status.success(), "compiling {source} failed");
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{} failed:\n{}",
+        program.display(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}